@@ -5,9 +5,8 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugin(UiBuilderPlugin)
-        .register_bind_data_source::<Inventory>(true)
-        .register_bind_data_source::<Item>(true)
-        .register_bind_component::<Inventory, Item>()
+        // bindings added by ui builder register their systems on demand,
+        // event still needs to be added
         .register_bind_event::<MyEvent, Inventory>()
         .add_startup_system(setup)
         .run();
@@ -26,26 +25,30 @@ pub struct MyEvent;
 
 fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn(Camera2dBundle::default());
-    let mut b = UiBuilder::new(&mut commands, ());
-    b.set_default_text_style(TextStyle {
+    let text_style = TextStyle {
         font: asset_server.load("fonts/FiraMono-Medium.ttf"),
         font_size: 24.,
         color: Color::BLACK,
-    });
+    };
+    //
+    // text style as context, list binding item builder gets a clone of it,
+    // handlers with `_ctx` suffix read it
+    //
+    let mut b = UiBuilder::new(&mut commands, text_style.clone());
+    b.set_default_text_style(text_style);
 
     let inventory = Inventory {
         items: vec![Item("a".into(), 1), Item("b".into(), 3)],
     };
 
-    let mut inventory_entity = Entity::from_raw(u32::MAX);
     b.node()
-        .with_name("inventory")
-        .pull_last(&mut inventory_entity)
-        .with_component(inventory)
         .with_style_modifier((StyleSize::FULL, StyleCenterChildren, FlexDirection::Column))
         .with_children(|b| {
+            //
+            // "inventory" is declared below, resolved by finish()
+            //
             b.text("").with_bind_source(
-                inventory_entity,
+                "inventory",
                 |_, inventory: &Inventory, mut text: Mut<Text>| {
                     text.sections[0].value = format!(
                         "total num: {}",
@@ -54,42 +57,54 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 },
             );
 
-            let mut items_entity = Entity::from_raw(u32::MAX);
-            let text_style = b.default_text_style.clone();
+            b.node()
+                .with_unique_name("inventory")
+                .with_component(inventory);
+
+            //
+            // children are diffed by item name, only added or removed items
+            // are spawned or despawned
+            //
             b.node()
                 .with_name("items")
-                .pull_last(&mut items_entity)
                 .with_style_modifier((StyleCenterChildren, FlexDirection::Column))
-                .with_on_source_change(
-                    inventory_entity,
-                    move |commands: &mut Commands, inventory: &Inventory| {
-                        commands.entity(items_entity).despawn_descendants();
-
-                        let mut b = UiBuilder::new(commands, ());
-                        b.set_default_text_style(text_style.clone());
-                        b.set_parent(items_entity);
-
-                        for (idx, item) in inventory.items.iter().enumerate() {
-                            b.node()
-                                .with_name(format!("item{}", idx))
-                                .with_component(item.clone())
-                                .with_bind_source(
-                                    inventory_entity,
-                                    move |_, inventory: &Inventory, mut item: Mut<Item>| {
-                                        *item = inventory.items[idx].clone();
+                .with_list_binding(
+                    "inventory",
+                    |inventory: &Inventory| &inventory.items,
+                    |item: &Item| item.0.clone(),
+                    |b, item: &Item| {
+                        let name = item.0.clone();
+                        b.node()
+                            .with_name(format!("item {}", item.0))
+                            .with_component(item.clone())
+                            //
+                            // item builder runs later, "inventory" is found
+                            // in UiNames
+                            //
+                            .with_bind_source(
+                                "inventory",
+                                move |_, inventory: &Inventory, mut item: Mut<Item>| {
+                                    if let Some(i) = inventory.items.iter().find(|i| i.0 == name) {
+                                        *item = i.clone();
+                                    }
+                                },
+                            )
+                            .with_children(|b| {
+                                let parent = b.parent();
+                                //
+                                // `_ctx` handler reads text style from context,
+                                // large amounts are highlighted
+                                //
+                                b.text("").with_bind_source_ctx(
+                                    parent,
+                                    |_, item: &Item, mut text: Mut<Text>, style: &TextStyle| {
+                                        text.sections[0].value =
+                                            format!("name:{} amount:{}", item.0, item.1);
+                                        text.sections[0].style.color =
+                                            if item.1 > 3 { Color::RED } else { style.color };
                                     },
-                                )
-                                .with_children(|b| {
-                                    let parent = b.parent();
-                                    b.text("").with_bind_source(
-                                        parent,
-                                        |_, item: &Item, mut text: Mut<Text>| {
-                                            text.sections[0].value =
-                                                format!("name:{} amount:{}", item.0, item.1);
-                                        },
-                                    );
-                                });
-                        }
+                                );
+                            });
                     },
                 );
 
@@ -99,17 +114,20 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 .with_style_modifier((StyleCenterChildren, StyleMargin::all_px(15.0)))
                 .with_send_event_click(MyEvent)
                 .with_event_bind_to_target(
-                    inventory_entity,
+                    "inventory",
                     |_commands, _ev: &MyEvent, mut inventory: Mut<Inventory>| {
                         info!("button clicked");
                         for item in inventory.as_mut().items.iter_mut() {
                             item.1 += 1;
                         }
-                        inventory.items.push(Item("c".into(), 1));
+                        if !inventory.items.iter().any(|i| i.0 == "c") {
+                            inventory.items.push(Item("c".into(), 1));
+                        }
                     },
                 )
                 .with_children(|b| {
                     b.text("all amount++\nadd item c");
                 });
         });
+    b.finish().unwrap();
 }
@@ -8,7 +8,7 @@ fn main() {
         //
         // register data component
         //
-        .register_bind_data_source::<Counter>(true)
+        .register_bind_data_source::<Counter>(false)
         .add_startup_system(setup)
         .add_event::<MyClickEvent>()
         .add_system(handle_my_click_event)
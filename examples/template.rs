@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use bevy_ui_builder::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(AssetPlugin {
+            watch_for_changes: true,
+            ..default()
+        }))
+        .add_plugin(UiBuilderPlugin)
+        .add_startup_system(setup)
+        .add_system(print_toggle)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let mut b = UiBuilder::new(&mut commands, ());
+    b.node()
+        .with_name("ui-root")
+        .with_style_modifier(StyleSize::FULL)
+        //
+        // spawn template as children when assets/ui/menu.ui.ron is loaded
+        //
+        .with_template(asset_server.load("ui/menu.ui.ron"));
+}
+
+fn print_toggle(query: Query<(&Name, &ToggleButton), Changed<ToggleButton>>) {
+    for (name, toggle) in query.iter() {
+        info!("{} toggled: {}", name, toggle.toggled);
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use bevy_ui_builder::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(UiBuilderPlugin)
+        .add_startup_system(setup)
+        .run();
+}
+
+/// custom widget, fields are props
+struct LabeledCounter {
+    label: String,
+    value: i32,
+}
+
+impl<C> UiWidget<C> for LabeledCounter {
+    fn build<B: UiBuilderBackend>(self, b: &mut UiBuilder<B, C>) {
+        b.node()
+            .with_style_modifier((FlexDirection::Row, StyleMargin::all_px(5.)))
+            .with_children(|b| {
+                b.text(self.label);
+                b.text(format!(": {}", self.value));
+            });
+    }
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let mut b = UiBuilder::new(&mut commands, ());
+    b.set_default_text_style(TextStyle {
+        font: asset_server.load("fonts/FiraMono-Medium.ttf"),
+        font_size: 24.,
+        color: Color::BLACK,
+    });
+
+    let colors = ColorButton {
+        normal: Color::WHITE,
+        disabled: Color::GRAY,
+        pressed: Color::GREEN,
+        hovered: Color::ORANGE,
+        pressed_hovered: Color::SEA_GREEN,
+    };
+
+    b.node()
+        .with_name("ui-root")
+        .with_style_modifier((StyleSize::FULL, StyleCenterChildren, FlexDirection::Column))
+        .with_children(|b| {
+            b.widget(LabeledCounter {
+                label: "apples".into(),
+                value: 3,
+            });
+
+            b.widget(ColorButtonWidget {
+                colors: colors.clone(),
+                label: Some("click".into()),
+                ..default()
+            })
+            // last entity is widget root
+            .with_name("click-button")
+            .with_on_button_click(|_, info: &ButtonClickInfo| {
+                info!("clicked {:?}", info.name);
+            });
+
+            for name in ["a", "b", "c"] {
+                b.widget(ToggleButtonWidget {
+                    group: Some("group".into()),
+                    button: ColorButtonWidget {
+                        colors: colors.clone(),
+                        label: Some(name.into()),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_name(name);
+            }
+        });
+}
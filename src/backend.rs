@@ -0,0 +1,181 @@
+use bevy::{
+    ecs::system::{Command, Insert},
+    prelude::*,
+};
+
+/// spawning backend of UiBuilder
+///
+/// implemented for `Commands`, `World` and `ChildBuilder`
+pub trait UiBuilderBackend {
+    /// spawn new entity with bundle
+    fn spawn(&mut self, bundle: impl Bundle) -> Entity;
+
+    /// insert or overwrite bundle of entity
+    fn insert(&mut self, entity: Entity, bundle: impl Bundle);
+
+    /// push child to the back of parent children
+    fn add_child(&mut self, parent: Entity, child: Entity);
+
+    /// run command, deferred except World backend
+    fn add_command(&mut self, command: impl Command);
+}
+
+impl<'w, 's> UiBuilderBackend for Commands<'w, 's> {
+    fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        Commands::spawn(self, bundle).id()
+    }
+
+    fn insert(&mut self, entity: Entity, bundle: impl Bundle) {
+        self.entity(entity).insert(bundle);
+    }
+
+    fn add_child(&mut self, parent: Entity, child: Entity) {
+        self.entity(parent).add_child(child);
+    }
+
+    fn add_command(&mut self, command: impl Command) {
+        self.add(command);
+    }
+}
+
+/// entities are available right away, spawning is synchronous
+impl UiBuilderBackend for World {
+    fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        World::spawn(self, bundle).id()
+    }
+
+    fn insert(&mut self, entity: Entity, bundle: impl Bundle) {
+        self.entity_mut(entity).insert(bundle);
+    }
+
+    fn add_child(&mut self, parent: Entity, child: Entity) {
+        self.entity_mut(parent).push_children(&[child]);
+    }
+
+    fn add_command(&mut self, command: impl Command) {
+        command.write(self);
+    }
+}
+
+/// nodes without parent become children of ChildBuilder parent entity
+///
+/// ChildBuilder makes every spawned entity its child when it is dropped, so
+/// other parents are applied later by `ui_builder_pending_children_system`
+impl<'w, 's, 'a> UiBuilderBackend for ChildBuilder<'w, 's, 'a> {
+    fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        ChildBuilder::spawn(self, bundle).id()
+    }
+
+    fn insert(&mut self, entity: Entity, bundle: impl Bundle) {
+        ChildBuilder::add_command(self, Insert { entity, bundle });
+    }
+
+    fn add_child(&mut self, parent: Entity, child: Entity) {
+        if parent == self.parent_entity() {
+            return;
+        }
+        ChildBuilder::add_command(self, move |world: &mut World| {
+            world
+                .get_resource_or_insert_with(UiBuilderPendingChildren::default)
+                .0
+                .push((parent, child));
+        });
+    }
+
+    fn add_command(&mut self, command: impl Command) {
+        ChildBuilder::add_command(self, command);
+    }
+}
+
+/// (parent, child) pairs from ChildBuilder backend waiting to be applied
+#[derive(Resource, Debug, Default)]
+pub struct UiBuilderPendingChildren(pub Vec<(Entity, Entity)>);
+
+pub fn ui_builder_pending_children_system(world: &mut World) {
+    let Some(mut pending) = world.get_resource_mut::<UiBuilderPendingChildren>() else {
+        return;
+    };
+    if pending.0.is_empty() {
+        return;
+    }
+    for (parent, child) in std::mem::take(&mut pending.0) {
+        if world.get_entity(parent).is_some() && world.get_entity(child).is_some() {
+            world.entity_mut(parent).push_children(&[child]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{builder::UiBuilder, UiBuilderPlugin};
+
+    #[derive(Component)]
+    struct Root;
+
+    #[derive(Component)]
+    struct Inner;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(bevy::input::InputPlugin)
+            .add_plugin(UiBuilderPlugin);
+        app
+    }
+
+    fn build<B: UiBuilderBackend>(backend: &mut B) {
+        let mut b = UiBuilder::new(backend, ());
+        b.node().with_component(Root).with_children(|b| {
+            b.node();
+            b.node().with_children(|b| {
+                b.node().with_component(Inner);
+            });
+        });
+        b.finish().unwrap();
+    }
+
+    fn find<T: Component>(app: &mut App) -> Entity {
+        app.world
+            .query_filtered::<Entity, With<T>>()
+            .single(&app.world)
+    }
+
+    /// Root -> [_, _ -> Inner]
+    fn assert_hierarchy(app: &mut App) -> Entity {
+        let root = find::<Root>(app);
+        let inner = find::<Inner>(app);
+        let children = app.world.get::<Children>(root).unwrap().to_vec();
+        assert_eq!(children.len(), 2);
+        assert_eq!(app.world.get::<Parent>(inner).unwrap().get(), children[1]);
+        root
+    }
+
+    #[test]
+    fn world_backend_spawns_right_away() {
+        let mut app = test_app();
+        build(&mut app.world);
+        assert_hierarchy(&mut app);
+    }
+
+    #[test]
+    fn commands_backend() {
+        let mut app = test_app();
+        app.add_startup_system(|mut commands: Commands| build(&mut commands));
+        app.update();
+        assert_hierarchy(&mut app);
+    }
+
+    #[test]
+    fn child_builder_backend() {
+        let mut app = test_app();
+        app.add_startup_system(|mut commands: Commands| {
+            commands.spawn_empty().with_children(|cb| build(cb));
+        });
+        app.update();
+        let root = assert_hierarchy(&mut app);
+        let parent = app.world.get::<Parent>(root).unwrap().get();
+        assert_eq!(app.world.get::<Children>(parent).unwrap().len(), 1);
+    }
+}
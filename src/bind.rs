@@ -1,70 +1,251 @@
+use std::{
+    any::{type_name, TypeId},
+    hash::Hash,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
 use bevy::{
-    ecs::event::Event,
+    asset::{Asset, HandleId},
+    ecs::{
+        archetype::Archetypes,
+        component::Components,
+        entity::Entities,
+        event::{Event, ManualEventReader},
+        schedule::IntoSystemDescriptor,
+        system::SystemParam,
+    },
     prelude::*,
-    ui::{widget::ImageMode, FocusPolicy},
+    utils::{HashMap, HashSet},
+};
+use smallvec::{smallvec, SmallVec};
+
+use crate::{
+    backend::UiBuilderBackend,
+    builder::{run_world_handler, HandlerSystem, UiBuilder, UiLastBinding, WorldHandler},
+    error::UiBuilderError,
+    names::UiEntityRef,
+    observable::{register_observable_vec_trim, ListOp, ListOpsCursor, ObservableVec},
 };
 
-use crate::builder::UiBuilder;
+/// fn(commands, changed value)
+pub type ChangeHandler<S> = Box<dyn Fn(&mut Commands, &S) + 'static + Send + Sync>;
+
+/// fn(commands, changed value, component to modify)
+pub type BindHandler<S, T> = Box<dyn Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync>;
+
+/// entities whose component `S` or binding `B` changed
+pub(crate) type ChangedBindings<'w, 's, S, B> = Query<'w, 's, Entity, Or<(Changed<S>, Changed<B>)>>;
+
+/// bindings `B` added or modified, synced once from current source value
+pub(crate) type NewBindings<'w, 's, B> = Query<'w, 's, (Entity, &'static B), Changed<B>>;
+
+/// changed source entities, and source values with change trackers
+pub(crate) type SourceQueries<'w, 's, S> = (
+    Query<'w, 's, Entity, Changed<S>>,
+    Query<'w, 's, (&'static S, ChangeTrackers<S>)>,
+);
 
-/// when current entity component `S` change, call handler function
+/// when current entity component `S` change, call handler function, also
+/// called once when added
 ///
 /// app.register_bind_data_source::<S>() is needed
 #[derive(Component)]
-pub struct OnSelfChange<S: Component>(pub Box<dyn Fn(&mut Commands, &S) + 'static + Send + Sync>);
+pub struct OnSelfChange<S: Component>(pub ChangeHandler<S>);
 
 pub fn on_self_change_system<S: Component>(
     mut commands: Commands,
-    mut query: Query<(&S, &OnSelfChange<S>), Changed<S>>,
+    propagation: Res<BindPropagation>,
+    mut rates: BindRates<OnSelfChange<S>>,
+    changed_query: ChangedBindings<S, OnSelfChange<S>>,
+    query: Query<(&S, &OnSelfChange<S>)>,
 ) {
-    for (s, on_change) in query.iter_mut() {
+    let mut fired: Vec<Entity> = changed_query.iter().collect();
+    rates.filter(&mut fired);
+    for (s, on_change) in query.iter_many(&fired) {
         (on_change.0)(&mut commands, s);
+        propagation.mark_fired();
     }
 }
 
-/// when remote entity `source` component `S` change, call handler function
+/// when remote entity `source` component `S` change, call handler function,
+/// also called once when added or `source` changed
 ///
 /// app.register_bind_data_source::<S>() is needed
 #[derive(Component)]
 pub struct OnSourceChange<S: Component> {
     pub source: Entity,
-    pub handler: Box<dyn Fn(&mut Commands, &S) + 'static + Send + Sync>,
+    pub handler: ChangeHandler<S>,
 }
 
 pub fn on_source_change_system<S: Component>(
     mut commands: Commands,
-    target_query: Query<(Entity, &S), Changed<S>>,
-    mut query: Query<&OnSourceChange<S>>,
+    propagation: Res<BindPropagation>,
+    mut rates: BindRates<OnSourceChange<S>>,
+    index: Res<BindingIndex<OnSourceChange<S>>>,
+    (changed_source_query, source_query): SourceQueries<S>,
+    query: Query<&OnSourceChange<S>>,
+    changed_query: NewBindings<OnSourceChange<S>>,
 ) {
-    for (source_entity, s) in target_query.iter() {
-        for on_remote_change in query.iter_mut() {
-            if on_remote_change.source == source_entity {
-                (on_remote_change.handler)(&mut commands, s);
+    let mut fired: Vec<Entity> = changed_source_query
+        .iter()
+        .flat_map(|source_entity| index.get(source_entity).iter().copied())
+        .collect();
+    // initial sync, changed sources are already collected above
+    for (binding_entity, on_remote_change) in changed_query.iter() {
+        if let Ok((_, tracker)) = source_query.get(on_remote_change.source) {
+            if !tracker.is_changed() {
+                fired.push(binding_entity);
             }
         }
     }
+    rates.filter(&mut fired);
+
+    for on_remote_change in query.iter_many(&fired) {
+        if let Ok((s, _)) = source_query.get(on_remote_change.source) {
+            (on_remote_change.handler)(&mut commands, s);
+            propagation.mark_fired();
+        }
+    }
 }
 
-/// when event E happen, call handler function to modify entity `target`
-/// component `T`
+/// when event E happen, call handler function to modify component `T` of
+/// `target` entities, events have no current value, so no initial call
 ///
 /// app.register_bind_event::<E, T>()
 #[derive(Component)]
 pub struct EventBindToTarget<E: Event, T: Component> {
-    pub target: Entity,
-    pub handler: Box<dyn Fn(&mut Commands, &E, Mut<T>) + 'static + Send + Sync>,
+    pub target: EventBindTarget,
+    /// only events passing filter are handled
+    pub filter: Option<EventFilter<E>>,
+    /// target entity carried by event, only this entity is modified if it is
+    /// one of `target` entities, events without target entity are skipped
+    pub event_target: Option<EventTargetFn<E>>,
+    pub handler: BindHandler<E, T>,
+}
+
+/// fn(event), return false to skip event
+pub type EventFilter<E> = Box<dyn Fn(&E) -> bool + 'static + Send + Sync>;
+
+/// fn(event), return target entity carried by event
+pub type EventTargetFn<E> = Box<dyn Fn(&E) -> Option<Entity> + 'static + Send + Sync>;
+
+impl<E: Event, T: Component> EventBindToTarget<E, T> {
+    pub fn new(
+        target: impl Into<EventBindTarget>,
+        handler: impl Fn(&mut Commands, &E, Mut<T>) + 'static + Send + Sync,
+    ) -> Self {
+        Self {
+            target: target.into(),
+            filter: None,
+            event_target: None,
+            handler: Box::new(handler),
+        }
+    }
+
+    /// handle events passing filter only
+    pub fn with_filter(mut self, filter: impl Fn(&E) -> bool + 'static + Send + Sync) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// modify target entity carried by event only, e.g. `|ev| Some(ev.slot)`
+    pub fn with_event_target(
+        mut self,
+        event_target: impl Fn(&E) -> Option<Entity> + 'static + Send + Sync,
+    ) -> Self {
+        self.event_target = Some(Box::new(event_target));
+        self
+    }
+}
+
+/// entities modified by EventBindToTarget
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventBindTarget {
+    Entity(Entity),
+    /// every entity with marker component, see EventBindTarget::marker()
+    Marker(TypeId),
+    /// every entity with `Name` starting with prefix
+    NamePrefix(String),
+}
+
+impl EventBindTarget {
+    /// every entity with marker component `M`
+    pub fn marker<M: Component>() -> Self {
+        EventBindTarget::Marker(TypeId::of::<M>())
+    }
+
+    /// every entity with `Name` starting with prefix
+    pub fn name_prefix(prefix: impl Into<String>) -> Self {
+        EventBindTarget::NamePrefix(prefix.into())
+    }
+}
+
+impl From<Entity> for EventBindTarget {
+    fn from(entity: Entity) -> Self {
+        EventBindTarget::Entity(entity)
+    }
 }
 
+/// does nothing until event `E` is added
 pub fn event_bind_to_target_system<E: Event, T: Component>(
     mut commands: Commands,
-    mut event_reader: EventReader<E>,
+    propagation: Res<BindPropagation>,
+    (events, mut event_reader): (Option<Res<Events<E>>>, Local<ManualEventReader<E>>),
+    (entities, archetypes, components): (&Entities, &Archetypes, &Components),
     event_bind_query: Query<&EventBindToTarget<E, T>>,
-    mut target_query: Query<(Entity, &mut T)>,
+    mut target_query: Query<(Entity, &mut T, Option<&Name>)>,
 ) {
-    for ev in event_reader.iter() {
+    let Some(events) = events else {
+        return;
+    };
+    for ev in event_reader.iter(&events) {
         for event_bind in event_bind_query.iter() {
-            for (target_entity, t) in target_query.iter_mut() {
-                if event_bind.target == target_entity {
+            if !event_bind.filter.as_ref().is_none_or(|filter| filter(ev)) {
+                continue;
+            }
+            let event_target = match &event_bind.event_target {
+                Some(event_target) => match event_target(ev) {
+                    Some(entity) => Some(entity),
+                    None => continue,
+                },
+                None => None,
+            };
+            let is_target = |entity: Entity, name: Option<&Name>| match &event_bind.target {
+                EventBindTarget::Entity(target) => entity == *target,
+                EventBindTarget::Marker(type_id) => components
+                    .get_id(*type_id)
+                    .zip(entities.get(entity))
+                    .is_some_and(|(component_id, location)| {
+                        archetypes[location.archetype_id].contains(component_id)
+                    }),
+                EventBindTarget::NamePrefix(prefix) => {
+                    name.is_some_and(|name| name.as_str().starts_with(prefix.as_str()))
+                }
+            };
+
+            let single_target = match (&event_bind.target, event_target) {
+                (_, Some(entity)) => Some(entity),
+                (EventBindTarget::Entity(entity), None) => Some(*entity),
+                _ => None,
+            };
+            if let Some(entity) = single_target {
+                if let Ok((entity, t, name)) = target_query.get_mut(entity) {
+                    if is_target(entity, name) {
+                        (event_bind.handler)(&mut commands, ev, t);
+                        propagation.mark_fired();
+                    }
+                }
+                continue;
+            }
+            for (entity, t, name) in target_query.iter_mut() {
+                if is_target(entity, name) {
                     (event_bind.handler)(&mut commands, ev, t);
+                    propagation.mark_fired();
                 }
             }
         }
@@ -72,25 +253,31 @@ pub fn event_bind_to_target_system<E: Event, T: Component>(
 }
 
 /// when current entity component `S` change, call handler function to modify
-/// current entity component `T`
+/// current entity component `T`, also called once when added
 ///
 /// app.register_bind_component::<S, T>() is needed
 #[derive(Component)]
-pub struct SelfBind<S: Component, T: Component>(
-    pub Box<dyn Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync>,
-);
+pub struct SelfBind<S: Component, T: Component>(pub BindHandler<S, T>);
 
 pub fn self_bind_system<S: Component, T: Component>(
     mut commands: Commands,
-    mut query: Query<(&S, &SelfBind<S, T>, &mut T), Changed<S>>,
+    propagation: Res<BindPropagation>,
+    mut rates: BindRates<SelfBind<S, T>>,
+    changed_query: ChangedBindings<S, SelfBind<S, T>>,
+    mut query: Query<(&S, &SelfBind<S, T>, &mut T)>,
 ) {
-    for (s, self_bind, t) in query.iter_mut() {
-        (self_bind.0)(&mut commands, s, t);
+    let mut fired: Vec<Entity> = changed_query.iter().collect();
+    rates.filter(&mut fired);
+    for entity in fired {
+        if let Ok((s, self_bind, t)) = query.get_mut(entity) {
+            (self_bind.0)(&mut commands, s, t);
+            propagation.mark_fired();
+        }
     }
 }
 
 /// when current entity component `S` change, call handler function to modify
-/// remote entity `target` component `T`
+/// remote entity `target` component `T`, also called once when added
 ///
 /// app.register_bind_component::<S, T>() is needed
 #[derive(Component)]
@@ -102,48 +289,832 @@ pub struct BindToTarget<S: Component, T: Component>(pub Vec<BindToTargetItem<S,
 /// app.register_bind_component::<S, T>() is needed
 pub struct BindToTargetItem<S: Component, T: Component> {
     pub target: Entity,
-    pub handler: Box<dyn Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync>,
+    pub handler: BindHandler<S, T>,
 }
 
 pub fn bind_to_target_system<S: Component, T: Component>(
     mut commands: Commands,
-    mut query: Query<(&S, &BindToTarget<S, T>), Changed<S>>,
+    propagation: Res<BindPropagation>,
+    mut rates: BindRates<BindToTarget<S, T>>,
+    changed_query: ChangedBindings<S, BindToTarget<S, T>>,
+    query: Query<(&S, &BindToTarget<S, T>)>,
     mut target_query: Query<&mut T>,
 ) {
-    for (s, bind_to_target) in query.iter_mut() {
+    let mut fired: Vec<Entity> = changed_query.iter().collect();
+    rates.filter(&mut fired);
+    for (s, bind_to_target) in query.iter_many(&fired) {
         for item in bind_to_target.0.iter() {
             if let Ok(t) = target_query.get_mut(item.target) {
                 (item.handler)(&mut commands, s, t);
+                propagation.mark_fired();
             }
         }
     }
 }
 
 /// when remote entity `source` component `S` change, call handler function to
-/// modify current entity component `T`
+/// modify current entity component `T`, also called once when added or
+/// `source_entity` changed
 ///
 /// app.register_bind_component::<S, T>() is needed
 #[derive(Component)]
 pub struct BindSource<S: Component, T: Component> {
     pub source_entity: Entity,
-    pub handler: Box<dyn Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync>,
+    pub handler: BindHandler<S, T>,
 }
 
 pub fn bind_source_system<S: Component, T: Component>(
     mut commands: Commands,
-    mut source_query: Query<(Entity, &S), Changed<S>>,
+    propagation: Res<BindPropagation>,
+    mut rates: BindRates<BindSource<S, T>>,
+    index: Res<BindingIndex<BindSource<S, T>>>,
+    (changed_source_query, source_query): SourceQueries<S>,
     mut target_query: Query<(&BindSource<S, T>, &mut T)>,
+    changed_query: NewBindings<BindSource<S, T>>,
+) {
+    let mut fired: Vec<Entity> = changed_source_query
+        .iter()
+        .flat_map(|source_entity| index.get(source_entity).iter().copied())
+        .collect();
+    // initial sync, changed sources are already collected above
+    for (binding_entity, bind_source) in changed_query.iter() {
+        if let Ok((_, tracker)) = source_query.get(bind_source.source_entity) {
+            if !tracker.is_changed() {
+                fired.push(binding_entity);
+            }
+        }
+    }
+    rates.filter(&mut fired);
+
+    for binding_entity in fired {
+        let Ok((bind_source, t)) = target_query.get_mut(binding_entity) else {
+            continue;
+        };
+        if let Ok((s, _)) = source_query.get(bind_source.source_entity) {
+            (bind_source.handler)(&mut commands, s, t);
+            propagation.mark_fired();
+        }
+    }
+}
+
+/// when remote entity `source` component `S` change, call to_widget to modify
+/// current entity component `T`, when `T` change, call from_widget to modify
+/// `S`
+///
+/// change written by one side does not fire the other handler back, if both
+/// sides change in the same frame, `S` wins. to_widget is also called once
+/// when added or `source` changed
+///
+/// app.register_bind_component::<S, T>() is needed
+#[derive(Component)]
+pub struct TwoWayBind<S: Component, T: Component> {
+    pub source: Entity,
+    pub to_widget: BindHandler<S, T>,
+    pub from_widget: BindHandler<T, S>,
+}
+
+/// widgets of two way bindings, with binding change trackers
+type TwoWayWidgets<'w, 's, S, T> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static TwoWayBind<S, T>,
+        ChangeTrackers<TwoWayBind<S, T>>,
+        &'static mut T,
+    ),
+>;
+
+pub fn two_way_bind_system<S: Component, T: Component>(
+    mut commands: Commands,
+    propagation: Res<BindPropagation>,
+    mut source_query: Query<&mut S>,
+    mut widget_query: TwoWayWidgets<S, T>,
+) {
+    // source -> widget entity which wrote it, None if changed outside
+    let mut changed_sources: HashMap<Entity, Option<Entity>> = HashMap::default();
+    for (_, bind, _, _) in widget_query.iter() {
+        if let Ok(s) = source_query.get_mut(bind.source) {
+            if s.is_changed() {
+                changed_sources.insert(bind.source, None);
+            }
+        }
+    }
+
+    // new binding is synced from source, widget placeholder is not written back
+    for (widget_entity, bind, bind_tracker, t) in widget_query.iter_mut() {
+        if !t.is_changed()
+            || bind_tracker.is_changed()
+            || changed_sources.contains_key(&bind.source)
+        {
+            continue;
+        }
+        if let Ok(s) = source_query.get_mut(bind.source) {
+            (bind.from_widget)(&mut commands, &t, s);
+            propagation.mark_fired();
+            changed_sources.insert(bind.source, Some(widget_entity));
+        }
+    }
+
+    // writes of this system are not seen as changes in next frame, so widget
+    // written here does not write back
+    for (widget_entity, bind, bind_tracker, t) in widget_query.iter_mut() {
+        match changed_sources.get(&bind.source) {
+            None if !bind_tracker.is_changed() => continue,
+            Some(Some(writer)) if *writer == widget_entity => continue,
+            _ => {}
+        }
+        if let Ok(s) = source_query.get(bind.source) {
+            (bind.to_widget)(&mut commands, s, t);
+            propagation.mark_fired();
+        }
+    }
+}
+
+/// when resource `R` change, call handler function to modify current entity
+/// component `T`, also called once when added, handlers wait until `R` is
+/// inserted if it does not exist yet
+///
+/// app.register_bind_resource::<R, T>() is needed
+#[derive(Component)]
+pub struct BindResource<R: Resource, T: Component>(pub BindHandler<R, T>);
+
+pub fn bind_resource_system<R: Resource, T: Component>(
+    mut commands: Commands,
+    propagation: Res<BindPropagation>,
+    mut rates: BindRates<BindResource<R, T>>,
+    resource: Option<Res<R>>,
+    mut query: Query<(Entity, &BindResource<R, T>, &mut T)>,
+    changed_query: Query<Entity, Changed<BindResource<R, T>>>,
+) {
+    // resource inserted later is seen as changed, so all handlers run once
+    let Some(resource) = resource else {
+        return;
+    };
+    let mut fired: Vec<Entity> = if resource.is_changed() {
+        query.iter().map(|(entity, _, _)| entity).collect()
+    } else {
+        changed_query.iter().collect()
+    };
+    rates.filter(&mut fired);
+    for entity in fired {
+        if let Ok((_, bind_resource, t)) = query.get_mut(entity) {
+            (bind_resource.0)(&mut commands, &resource, t);
+            propagation.mark_fired();
+        }
+    }
+}
+
+/// when resource `R` change, call handler function, also called once when
+/// added or when `R` is inserted
+///
+/// systems are added by with_on_resource_change(), component inserted
+/// without UiBuilder needs app.register_binding::<OnResourceChange<R>>()
+#[derive(Component)]
+pub struct OnResourceChange<R: Resource>(pub ChangeHandler<R>);
+
+pub fn on_resource_change_system<R: Resource>(
+    mut commands: Commands,
+    propagation: Res<BindPropagation>,
+    mut rates: BindRates<OnResourceChange<R>>,
+    resource: Option<Res<R>>,
+    query: Query<(Entity, &OnResourceChange<R>)>,
+    changed_query: Query<Entity, Changed<OnResourceChange<R>>>,
+) {
+    // see bind_resource_system
+    let Some(resource) = resource else {
+        return;
+    };
+    let mut fired: Vec<Entity> = if resource.is_changed() {
+        query.iter().map(|(entity, _)| entity).collect()
+    } else {
+        changed_query.iter().collect()
+    };
+    rates.filter(&mut fired);
+    for (_, on_change) in query.iter_many(&fired) {
+        (on_change.0)(&mut commands, &resource);
+        propagation.mark_fired();
+    }
+}
+
+/// when asset `A` of handle is loaded or modified (e.g. hot reloaded), call
+/// handler function to modify current entity component `T`, also called once
+/// when added if asset is already loaded
+///
+/// binding keeps the handle, so asset stays loaded while binding exists
+///
+/// app.register_bind_asset::<A, T>() is needed
+#[derive(Component)]
+pub struct BindAsset<A: Asset, T: Component> {
+    pub handle: Handle<A>,
+    pub handler: BindHandler<A, T>,
+}
+
+/// asset events, their reader and assets, None if asset `A` is not added
+type AssetParams<'w, 's, A> = (
+    Option<Res<'w, Events<AssetEvent<A>>>>,
+    Local<'s, ManualEventReader<AssetEvent<A>>>,
+    Option<Res<'w, Assets<A>>>,
+);
+
+/// does nothing until asset `A` is added
+pub fn bind_asset_system<A: Asset, T: Component>(
+    mut commands: Commands,
+    propagation: Res<BindPropagation>,
+    mut rates: BindRates<BindAsset<A, T>>,
+    (asset_events, mut event_reader, assets): AssetParams<A>,
+    mut query: Query<(Entity, &BindAsset<A, T>, &mut T)>,
+    changed_query: Query<Entity, Changed<BindAsset<A, T>>>,
+) {
+    let (Some(asset_events), Some(assets)) = (asset_events, assets) else {
+        return;
+    };
+    let changed_assets: HashSet<HandleId> = event_reader
+        .iter(&asset_events)
+        .filter_map(|event| match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => Some(handle.id()),
+            AssetEvent::Removed { .. } => None,
+        })
+        .collect();
+    let mut fired: Vec<Entity> = query
+        .iter()
+        .filter(|(entity, bind, _)| {
+            changed_assets.contains(&bind.handle.id()) || changed_query.contains(*entity)
+        })
+        .map(|(entity, _, _)| entity)
+        .collect();
+    rates.filter(&mut fired);
+    for entity in fired {
+        let Ok((_, bind, t)) = query.get_mut(entity) else {
+            continue;
+        };
+        if let Some(asset) = assets.get(&bind.handle) {
+            (bind.handler)(&mut commands, asset, t);
+            propagation.mark_fired();
+        }
+    }
+}
+
+/// rate control of a binding, for sources changing every frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BindRate {
+    /// minimum interval between handler calls, last change in interval is
+    /// handled when interval ends
+    Throttle(Duration),
+    /// trailing debounce, handler is called when no change happened for
+    /// duration
+    Debounce(Duration),
+    /// handler is called at most once per N frames with last change
+    EveryFrames(u32),
+}
+
+/// rate control state of binding `B` on the same entity, inserted by
+/// UiBuilderBindExt::with_bind_rate()
+#[derive(Component)]
+pub struct BindRateLimit<B: Component> {
+    pub rate: BindRate,
+    /// time of last change not handled yet
+    pub pending: Option<f64>,
+    /// time and frame of last handler call
+    pub last_fired: Option<(f64, u64)>,
+    marker: PhantomData<B>,
+}
+
+impl<B: Component> BindRateLimit<B> {
+    pub fn new(rate: BindRate) -> Self {
+        Self {
+            rate,
+            pending: None,
+            last_fired: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// record change, return true if handler should be called now
+    pub fn update(&mut self, changed: bool, now: f64, frame: u64) -> bool {
+        if changed {
+            self.pending = Some(now);
+        }
+        let Some(changed_at) = self.pending else {
+            return false;
+        };
+        let due = match self.rate {
+            BindRate::Throttle(interval) => self
+                .last_fired
+                .is_none_or(|(fired_at, _)| now - fired_at >= interval.as_secs_f64()),
+            BindRate::Debounce(delay) => now - changed_at >= delay.as_secs_f64(),
+            BindRate::EveryFrames(frames) => self
+                .last_fired
+                .is_none_or(|(_, fired_frame)| frame - fired_frame >= frames as u64),
+        };
+        if due {
+            self.pending = None;
+            self.last_fired = Some((now, frame));
+        }
+        due
+    }
+}
+
+/// rate limits of binding `B`, used by bind systems
+///
+/// rates are not applied without `Time` resource (no TimePlugin)
+#[derive(SystemParam)]
+pub struct BindRates<'w, 's, B: Component> {
+    time: Option<Res<'w, Time>>,
+    propagation: Res<'w, BindPropagation>,
+    query: Query<'w, 's, (Entity, &'static mut BindRateLimit<B>)>,
+}
+
+impl<'w, 's, B: Component> BindRates<'w, 's, B> {
+    /// remove rate limited bindings from binding entities fired by change,
+    /// add rate limited bindings which are due
+    pub fn filter(&mut self, fired: &mut Vec<Entity>) {
+        let Some(time) = self.time.as_ref() else {
+            return;
+        };
+        if self.query.is_empty() {
+            return;
+        }
+        let now = time.elapsed_seconds_f64();
+        let changed: HashSet<Entity> = fired.iter().copied().collect();
+        fired.retain(|entity| !self.query.contains(*entity));
+        let frame = self.propagation.frame();
+        for (entity, mut rate_limit) in self.query.iter_mut() {
+            let changed = changed.contains(&entity);
+            if rate_limit
+                .bypass_change_detection()
+                .update(changed, now, frame)
+            {
+                fired.push(entity);
+            }
+        }
+    }
+}
+
+/// insert BindRateLimit<B>, see UiLastBinding
+pub fn insert_bind_rate<B: Component>(world: &mut World, entity: Entity, rate: BindRate) {
+    if let Some(mut entity) = world.get_entity_mut(entity) {
+        entity.insert(BindRateLimit::<B>::new(rate));
+    }
+}
+
+/// tuple of data source components `(S1, S2, ...)` for BindSources
+pub trait BindSourceTuple: Send + Sync + 'static {
+    /// source entities, one for each component
+    type Entities: Copy + Send + Sync + 'static;
+    /// current values of all sources
+    type Refs<'a>;
+
+    fn register<T: Component>(stage: &mut BindPropagationStage);
+}
+
+/// when any of remote entities `sources` component `S1, S2, ...` change, call
+/// handler function with all sources to modify current entity component `T`,
+/// also called once when added or `sources` changed
+///
+/// app.register_bind_sources::<(S1, S2, ...), T>() is needed
+#[derive(Component)]
+pub struct BindSources<S: BindSourceTuple, T: Component> {
+    pub sources: S::Entities,
+    pub handler: BindSourcesHandler<S, T>,
+}
+
+/// fn(commands, values of all sources, component to modify)
+pub type BindSourcesHandler<S, T> = Box<
+    dyn for<'s> Fn(&mut Commands, <S as BindSourceTuple>::Refs<'s>, Mut<T>) + 'static + Send + Sync,
+>;
+
+macro_rules! replace_entity {
+    ($_s: ident) => {
+        Entity
+    };
+}
+
+macro_rules! impl_bind_source_tuple {
+    ($(($s: ident, $q: ident, $r: ident, $e: ident, $v: ident, $c: ident)),*) => {
+        impl<$($s: Component),*> BindSourceTuple for ($($s,)*) {
+            type Entities = ($(replace_entity!($s),)*);
+            type Refs<'a> = ($(&'a $s,)*);
+
+            fn register<T: Component>(stage: &mut BindPropagationStage) {
+                fn bind_sources_system<T: Component, $($s: Component),*>(
+                    mut commands: Commands,
+                    propagation: Res<BindPropagation>,
+                    mut rates: BindRates<BindSources<($($s,)*), T>>,
+                    ($($q,)*): ($(Query<(&$s, ChangeTrackers<$s>)>,)*),
+                    mut query: Query<(
+                        Entity,
+                        &BindSources<($($s,)*), T>,
+                        ChangeTrackers<BindSources<($($s,)*), T>>,
+                        &mut T,
+                    )>,
+                ) {
+                    let mut fired = vec![];
+                    for (binding_entity, bind_sources, bind_tracker, _) in query.iter() {
+                        let ($($e,)*) = bind_sources.sources;
+                        $(let Ok((_, $c)) = $q.get($e) else {
+                            continue;
+                        };)*
+                        if bind_tracker.is_changed() $(|| $c.is_changed())* {
+                            fired.push(binding_entity);
+                        }
+                    }
+                    rates.filter(&mut fired);
+
+                    for binding_entity in fired {
+                        let Ok((_, bind_sources, _, t)) = query.get_mut(binding_entity) else {
+                            continue;
+                        };
+                        let ($($e,)*) = bind_sources.sources;
+                        $(let Ok(($v, _)) = $q.get($e) else {
+                            continue;
+                        };)*
+                        (bind_sources.handler)(&mut commands, ($($v,)*), t);
+                        propagation.mark_fired();
+                    }
+                }
+
+                fn bind_sources_cleanup_system<T: Component, $($s: Component),*>(
+                    mut commands: Commands,
+                    entities: &Entities,
+                    diagnostics: Res<BindingDiagnostics>,
+                    ($($r,)*): ($(RemovedComponents<$s>,)*),
+                    query: Query<(Entity, &BindSources<($($s,)*), T>)>,
+                    mut broken_events: EventWriter<BindingBroken>,
+                ) {
+                    // see binding_cleanup_system
+                    let mut despawned = HashSet::new();
+                    $(despawned.extend($r.iter().filter(|e| !entities.contains(*e)));)*
+                    if despawned.is_empty() {
+                        return;
+                    }
+                    for (binding_entity, bind_sources) in query.iter() {
+                        let ($($e,)*) = bind_sources.sources;
+                        let mut broken = false;
+                        for (remote_entity, remote_type) in [$(($e, type_name::<$s>())),*] {
+                            if !despawned.contains(&remote_entity) {
+                                continue;
+                            }
+                            send_binding_broken(
+                                &diagnostics,
+                                &mut broken_events,
+                                BindingBroken {
+                                    binding_entity,
+                                    remote_entity,
+                                    binding_type: type_name::<BindSources<($($s,)*), T>>(),
+                                    remote_type,
+                                },
+                            );
+                            broken = true;
+                        }
+                        if broken {
+                            commands
+                                .entity(binding_entity)
+                                .remove::<BindSources<($($s,)*), T>>();
+                        }
+                    }
+                }
+
+                stage.add_handler_system(bind_sources_system::<T, $($s),*>);
+                stage.add_cleanup(bind_sources_cleanup_system::<T, $($s),*>);
+            }
+        }
+    };
+}
+
+impl_bind_source_tuple!((S1, q1, r1, e1, v1, c1));
+impl_bind_source_tuple!((S1, q1, r1, e1, v1, c1), (S2, q2, r2, e2, v2, c2));
+impl_bind_source_tuple!(
+    (S1, q1, r1, e1, v1, c1),
+    (S2, q2, r2, e2, v2, c2),
+    (S3, q3, r3, e3, v3, c3)
+);
+impl_bind_source_tuple!(
+    (S1, q1, r1, e1, v1, c1),
+    (S2, q2, r2, e2, v2, c2),
+    (S3, q3, r3, e3, v3, c3),
+    (S4, q4, r4, e4, v4, c4)
+);
+impl_bind_source_tuple!(
+    (S1, q1, r1, e1, v1, c1),
+    (S2, q2, r2, e2, v2, c2),
+    (S3, q3, r3, e3, v3, c3),
+    (S4, q4, r4, e4, v4, c4),
+    (S5, q5, r5, e5, v5, c5)
+);
+impl_bind_source_tuple!(
+    (S1, q1, r1, e1, v1, c1),
+    (S2, q2, r2, e2, v2, c2),
+    (S3, q3, r3, e3, v3, c3),
+    (S4, q4, r4, e4, v4, c4),
+    (S5, q5, r5, e5, v5, c5),
+    (S6, q6, r6, e6, v6, c6)
+);
+impl_bind_source_tuple!(
+    (S1, q1, r1, e1, v1, c1),
+    (S2, q2, r2, e2, v2, c2),
+    (S3, q3, r3, e3, v3, c3),
+    (S4, q4, r4, e4, v4, c4),
+    (S5, q5, r5, e5, v5, c5),
+    (S6, q6, r6, e6, v6, c6),
+    (S7, q7, r7, e7, v7, c7)
+);
+impl_bind_source_tuple!(
+    (S1, q1, r1, e1, v1, c1),
+    (S2, q2, r2, e2, v2, c2),
+    (S3, q3, r3, e3, v3, c3),
+    (S4, q4, r4, e4, v4, c4),
+    (S5, q5, r5, e5, v5, c5),
+    (S6, q6, r6, e6, v6, c6),
+    (S7, q7, r7, e7, v7, c7),
+    (S8, q8, r8, e8, v8, c8)
+);
+
+/// binding component referencing a remote source entity, indexed by
+/// BindingIndex
+pub trait IndexedBinding: Component {
+    fn source(&self) -> Entity;
+}
+
+impl<S: Component> IndexedBinding for OnSourceChange<S> {
+    fn source(&self) -> Entity {
+        self.source
+    }
+}
+
+impl<S: Component, T: Component> IndexedBinding for BindSource<S, T> {
+    fn source(&self) -> Entity {
+        self.source_entity
+    }
+}
+
+/// reverse index from source entity to entities with binding component `B`,
+/// so source change only touches bindings depend on it
+///
+/// updated by binding_index_system when binding component is added or
+/// replaced, by binding_index_cleanup_system when it is removed, and by
+/// binding_cleanup_system when binding is broken
+#[derive(Resource)]
+pub struct BindingIndex<B: Component> {
+    bindings: HashMap<Entity, Vec<Entity>>,
+    sources: HashMap<Entity, Entity>,
+    marker: PhantomData<B>,
+}
+
+impl<B: Component> Default for BindingIndex<B> {
+    fn default() -> Self {
+        Self {
+            bindings: HashMap::default(),
+            sources: HashMap::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<B: Component> BindingIndex<B> {
+    /// get binding entities of source
+    pub fn get(&self, source: Entity) -> &[Entity] {
+        self.bindings.get(&source).map_or(&[], |v| v.as_slice())
+    }
+
+    /// get source of binding entity
+    pub fn source(&self, binding_entity: Entity) -> Option<Entity> {
+        self.sources.get(&binding_entity).copied()
+    }
+
+    /// iterate (source, binding entities) pairs
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &[Entity])> {
+        self.bindings.iter().map(|(s, v)| (*s, v.as_slice()))
+    }
+
+    /// index binding entity with source, replace old source
+    pub fn insert(&mut self, binding_entity: Entity, source: Entity) {
+        self.remove(binding_entity);
+        self.sources.insert(binding_entity, source);
+        self.bindings
+            .entry(source)
+            .or_default()
+            .push(binding_entity);
+    }
+
+    /// remove binding entity, return its source
+    pub fn remove(&mut self, binding_entity: Entity) -> Option<Entity> {
+        let source = self.sources.remove(&binding_entity)?;
+        if let Some(v) = self.bindings.get_mut(&source) {
+            v.retain(|e| *e != binding_entity);
+            if v.is_empty() {
+                self.bindings.remove(&source);
+            }
+        }
+        Some(source)
+    }
+}
+
+/// index added or replaced bindings, removed bindings stay in index until
+/// binding_index_cleanup_system, handlers skip them
+pub fn binding_index_system<B: IndexedBinding>(
+    mut index: ResMut<BindingIndex<B>>,
+    changed_query: Query<(Entity, &B), Changed<B>>,
+) {
+    for (binding_entity, binding) in changed_query.iter() {
+        index.insert(binding_entity, binding.source());
+    }
+}
+
+/// drop removed bindings from index, runs in UiBindCleanupStage where removal
+/// events of the whole frame are seen
+pub fn binding_index_cleanup_system<B: IndexedBinding>(
+    mut index: ResMut<BindingIndex<B>>,
+    binding_query: Query<(), With<B>>,
+    removed: RemovedComponents<B>,
+) {
+    for binding_entity in removed.iter() {
+        // removed and inserted again in this frame
+        if !binding_query.contains(binding_entity) {
+            index.remove(binding_entity);
+        }
+    }
+}
+
+/// binding component storing remote entities, bindings to despawned remote
+/// entities are removed by binding_cleanup_system
+pub trait RemoteBinding: Component {
+    /// component of remote entity, its removal triggers dangling check
+    type Remote: Component;
+
+    fn remotes(&self) -> SmallVec<[Entity; 1]>;
+
+    /// remove remote entity, return true if no remote entity left
+    fn remove_remote(&mut self, remote: Entity) -> bool;
+}
+
+impl<S: Component> RemoteBinding for OnSourceChange<S> {
+    type Remote = S;
+
+    fn remotes(&self) -> SmallVec<[Entity; 1]> {
+        smallvec![self.source]
+    }
+
+    fn remove_remote(&mut self, _remote: Entity) -> bool {
+        true
+    }
+}
+
+impl<S: Component, T: Component> RemoteBinding for BindSource<S, T> {
+    type Remote = S;
+
+    fn remotes(&self) -> SmallVec<[Entity; 1]> {
+        smallvec![self.source_entity]
+    }
+
+    fn remove_remote(&mut self, _remote: Entity) -> bool {
+        true
+    }
+}
+
+impl<S: Component, T: Component> RemoteBinding for BindToTarget<S, T> {
+    type Remote = T;
+
+    fn remotes(&self) -> SmallVec<[Entity; 1]> {
+        self.0.iter().map(|item| item.target).collect()
+    }
+
+    fn remove_remote(&mut self, remote: Entity) -> bool {
+        self.0.retain(|item| item.target != remote);
+        self.0.is_empty()
+    }
+}
+
+impl<S: Component, T: Component> RemoteBinding for TwoWayBind<S, T> {
+    type Remote = S;
+
+    fn remotes(&self) -> SmallVec<[Entity; 1]> {
+        smallvec![self.source]
+    }
+
+    fn remove_remote(&mut self, _remote: Entity) -> bool {
+        true
+    }
+}
+
+impl<E: Event, T: Component> RemoteBinding for EventBindToTarget<E, T> {
+    type Remote = T;
+
+    fn remotes(&self) -> SmallVec<[Entity; 1]> {
+        match self.target {
+            EventBindTarget::Entity(target) => smallvec![target],
+            _ => SmallVec::new(),
+        }
+    }
+
+    fn remove_remote(&mut self, _remote: Entity) -> bool {
+        true
+    }
+}
+
+/// sent when remote entity of a binding is despawned, binding is removed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingBroken {
+    /// entity with binding component
+    pub binding_entity: Entity,
+    /// despawned source or target entity
+    pub remote_entity: Entity,
+    /// binding component type name, e.g. `BindSource<Inventory, Text>`
+    pub binding_type: &'static str,
+    /// remote component type name
+    pub remote_type: &'static str,
+}
+
+/// binding diagnostics settings
+#[derive(Resource, Debug, Default, Clone)]
+pub struct BindingDiagnostics {
+    /// log warning when binding is broken
+    pub warn_broken: bool,
+}
+
+fn warn_binding_broken(diagnostics: &BindingDiagnostics, broken: &BindingBroken) {
+    if diagnostics.warn_broken {
+        warn!(
+            "binding broken, {:?} {} -> despawned {:?} {}",
+            broken.binding_entity, broken.binding_type, broken.remote_entity, broken.remote_type
+        );
+    }
+}
+
+fn send_binding_broken(
+    diagnostics: &BindingDiagnostics,
+    broken_events: &mut EventWriter<BindingBroken>,
+    broken: BindingBroken,
+) {
+    warn_binding_broken(diagnostics, &broken);
+    broken_events.send(broken);
+}
+
+/// same as send_binding_broken(), for exclusive systems
+pub(crate) fn send_binding_broken_world(world: &mut World, broken: BindingBroken) {
+    if let Some(diagnostics) = world.get_resource::<BindingDiagnostics>() {
+        warn_binding_broken(diagnostics, &broken);
+    }
+    world.send_event(broken);
+}
+
+/// remove bindings to remote entities despawned in this frame, runs in
+/// UiBindCleanupStage where removal events of the whole frame are seen
+pub fn binding_cleanup_system<B: RemoteBinding>(
+    mut commands: Commands,
+    (entities, removed): (&Entities, RemovedComponents<B::Remote>),
+    diagnostics: Res<BindingDiagnostics>,
+    mut query: Query<(Entity, &mut B)>,
+    mut index: Option<ResMut<BindingIndex<B>>>,
+    mut broken_events: EventWriter<BindingBroken>,
 ) {
-    for (source_entity, s) in source_query.iter_mut() {
-        for (bind_source, t) in target_query.iter_mut() {
-            if bind_source.source_entity == source_entity {
-                (bind_source.handler)(&mut commands, s, t);
+    let despawned: HashSet<Entity> = removed.iter().filter(|e| !entities.contains(*e)).collect();
+    if despawned.is_empty() {
+        return;
+    }
+    // indexed bindings are found by source, others are checked one by one
+    let broken: Vec<(Entity, Entity)> = match index.as_deref() {
+        Some(index) => despawned
+            .iter()
+            .flat_map(|remote| index.get(*remote).iter().map(|e| (*e, *remote)))
+            .collect(),
+        None => query
+            .iter()
+            .flat_map(|(binding_entity, binding)| {
+                binding
+                    .remotes()
+                    .into_iter()
+                    .filter(|remote| despawned.contains(remote))
+                    .map(move |remote| (binding_entity, remote))
+            })
+            .collect(),
+    };
+    for (binding_entity, remote_entity) in broken {
+        let Ok((_, mut binding)) = query.get_mut(binding_entity) else {
+            continue;
+        };
+        send_binding_broken(
+            &diagnostics,
+            &mut broken_events,
+            BindingBroken {
+                binding_entity,
+                remote_entity,
+                binding_type: type_name::<B>(),
+                remote_type: type_name::<B::Remote>(),
+            },
+        );
+        if binding.remove_remote(remote_entity) {
+            commands.entity(binding_entity).remove::<B>();
+            if let Some(index) = index.as_mut() {
+                index.remove(binding_entity);
             }
         }
     }
 }
 
 pub trait UiBuilderBindExt {
+    type Context;
+
     fn with_on_self_change<S: Component>(
         &mut self,
         handler: impl Fn(&mut Commands, &S) + 'static + Send + Sync,
@@ -151,13 +1122,18 @@ pub trait UiBuilderBindExt {
 
     fn with_event_bind_to_target<E: Event, T: Component>(
         &mut self,
-        target: Entity,
+        target: impl Into<UiEntityRef>,
         handler: impl Fn(&mut Commands, &E, Mut<T>) + 'static + Send + Sync,
     ) -> &mut Self;
 
+    fn with_event_bind<E: Event, T: Component>(
+        &mut self,
+        bind: EventBindToTarget<E, T>,
+    ) -> &mut Self;
+
     fn with_on_source_change<S: Component>(
         &mut self,
-        source: Entity,
+        source: impl Into<UiEntityRef>,
         handler: impl Fn(&mut Commands, &S) + 'static + Send + Sync,
     ) -> &mut Self;
 
@@ -168,7 +1144,7 @@ pub trait UiBuilderBindExt {
 
     fn with_bind_to_target<S: Component, T: Component>(
         &mut self,
-        source: Entity,
+        target: impl Into<UiEntityRef>,
         handler: impl Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync,
     ) -> &mut Self;
 
@@ -179,162 +1155,2359 @@ pub trait UiBuilderBindExt {
 
     fn with_bind_source<S: Component, T: Component>(
         &mut self,
-        source: Entity,
+        source: impl Into<UiEntityRef>,
         handler: impl Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync,
     ) -> &mut Self;
-}
 
-impl<'w, 's, 'a, C> UiBuilderBindExt for UiBuilder<'w, 's, 'a, C> {
-    /// when current entity component `S` change, call handler function
-    ///
-    /// app.register_bind_data_source::<S>() is needed
-    fn with_on_self_change<S: Component>(
+    fn with_two_way_bind<S: Component, T: Component>(
         &mut self,
-        handler: impl Fn(&mut Commands, &S) + 'static + Send + Sync,
-    ) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(OnSelfChange::<S>(Box::new(handler)));
-        self
-    }
+        source: impl Into<UiEntityRef>,
+        to_widget: impl Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync,
+        from_widget: impl Fn(&mut Commands, &T, Mut<S>) + 'static + Send + Sync,
+    ) -> &mut Self;
+
+    fn with_bind_sources<S: BindSourceTuple, T: Component>(
+        &mut self,
+        sources: S::Entities,
+        handler: impl for<'s> Fn(&mut Commands, S::Refs<'s>, Mut<T>) + 'static + Send + Sync,
+    ) -> &mut Self;
+
+    fn with_bind_resource<R: Resource, T: Component>(
+        &mut self,
+        handler: impl Fn(&mut Commands, &R, Mut<T>) + 'static + Send + Sync,
+    ) -> &mut Self;
+
+    fn with_bind_rate(&mut self, rate: BindRate) -> &mut Self;
+
+    fn with_on_resource_change<R: Resource>(
+        &mut self,
+        handler: impl Fn(&mut Commands, &R) + 'static + Send + Sync,
+    ) -> &mut Self;
+
+    fn with_on_self_change_world<S: Component>(
+        &mut self,
+        handler: impl Fn(&mut World, BindEntities) + 'static + Send + Sync,
+    ) -> &mut Self;
+
+    fn with_on_self_change_system<S: Component, P>(
+        &mut self,
+        system: impl IntoSystem<BindEntities, (), P>,
+    ) -> &mut Self;
+
+    fn with_on_source_change_world<S: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut World, BindEntities) + 'static + Send + Sync,
+    ) -> &mut Self;
+
+    fn with_on_source_change_system<S: Component, P>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        system: impl IntoSystem<BindEntities, (), P>,
+    ) -> &mut Self;
+
+    fn with_on_resource_change_world<R: Resource>(
+        &mut self,
+        handler: impl Fn(&mut World, Entity) + 'static + Send + Sync,
+    ) -> &mut Self;
+
+    fn with_on_resource_change_system<R: Resource, P>(
+        &mut self,
+        system: impl IntoSystem<Entity, (), P>,
+    ) -> &mut Self;
+
+    fn with_bind_asset<A: Asset, T: Component>(
+        &mut self,
+        handle: Handle<A>,
+        handler: impl Fn(&mut Commands, &A, Mut<T>) + 'static + Send + Sync,
+    ) -> &mut Self;
+
+    fn with_on_self_change_ctx<S: Component>(
+        &mut self,
+        handler: impl Fn(&mut Commands, &S, &Self::Context) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        Self::Context: Clone + Send + Sync + 'static;
+
+    fn with_event_bind_to_target_ctx<E: Event, T: Component>(
+        &mut self,
+        target: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, &E, Mut<T>, &Self::Context) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        Self::Context: Clone + Send + Sync + 'static;
+
+    fn with_on_source_change_ctx<S: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, &S, &Self::Context) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        Self::Context: Clone + Send + Sync + 'static;
+
+    fn with_self_bind_ctx<S: Component, T: Component>(
+        &mut self,
+        handler: impl Fn(&mut Commands, &S, Mut<T>, &Self::Context) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        Self::Context: Clone + Send + Sync + 'static;
+
+    fn with_bind_to_target_ctx<S: Component, T: Component>(
+        &mut self,
+        target: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, &S, Mut<T>, &Self::Context) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        Self::Context: Clone + Send + Sync + 'static;
+
+    fn with_bind_source_ctx<S: Component, T: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, &S, Mut<T>, &Self::Context) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        Self::Context: Clone + Send + Sync + 'static;
+
+    fn with_list_binding<S: Component, Item: 'static, Key: Eq + Hash + Send + Sync + 'static>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        items_fn: impl Fn(&S) -> &[Item] + 'static + Send + Sync,
+        key_fn: impl Fn(&Item) -> Key + 'static + Send + Sync,
+        build_item_fn: impl Fn(&mut UiBuilder<Commands, Self::Context>, &Item) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        Self::Context: Clone + Send + Sync + 'static;
+
+    fn with_bind_list_ops<Item: Send + Sync + 'static, T: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, &ObservableVec<Item>, &[ListOp], Mut<T>) + 'static + Send + Sync,
+    ) -> &mut Self;
+
+    fn with_observable_list_binding<Item: Send + Sync + 'static>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        build_item_fn: impl Fn(&mut UiBuilder<Commands, Self::Context>, &Item) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        Self::Context: Clone + Send + Sync + 'static;
+
+    fn with_if<S: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        predicate: impl Fn(&S) -> bool + 'static + Send + Sync,
+        build_fn: impl Fn(&mut UiBuilder<Commands, Self::Context>) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        Self::Context: Clone + Send + Sync + 'static;
+
+    fn with_if_visible<S: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        predicate: impl Fn(&S) -> bool + 'static + Send + Sync,
+        build_fn: impl Fn(&mut UiBuilder<Commands, Self::Context>) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        Self::Context: Clone + Send + Sync + 'static;
+}
+
+/// source and target entity passed to `&mut World` and system handlers, read
+/// source component and write target component with them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BindEntities {
+    pub source: Entity,
+    pub target: Entity,
+}
+
+impl BindEntities {
+    pub fn new(source: Entity, target: Entity) -> Self {
+        Self { source, target }
+    }
+}
+
+impl<'a, B: UiBuilderBackend, C> UiBuilder<'a, B, C> {
+    fn with_on_source_change_handler<S: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, BindEntities) + 'static + Send + Sync,
+    ) -> &mut Self {
+        let Some(target) = self.last_or_report() else {
+            return self;
+        };
+        self.insert_last_with_ref(source, move |source| OnSourceChange::<S> {
+            source,
+            handler: Box::new(move |commands, _| {
+                handler(commands, BindEntities::new(source, target))
+            }),
+        });
+        self.register_binding::<OnSourceChange<S>>();
+        self
+    }
+}
+
+impl<'a, B: UiBuilderBackend, C> UiBuilderBindExt for UiBuilder<'a, B, C> {
+    type Context = C;
+
+    /// when current entity component `S` change, call handler function
+    fn with_on_self_change<S: Component>(
+        &mut self,
+        handler: impl Fn(&mut Commands, &S) + 'static + Send + Sync,
+    ) -> &mut Self {
+        self.insert_last(OnSelfChange::<S>(Box::new(handler)));
+        self.register_binding::<OnSelfChange<S>>();
+        self
+    }
 
     /// when event E happen, call handler function to modify entity `target`
     /// component `T`
     ///
-    /// app.register_bind_event::<E, T>()
+    /// `target` can be unique name declared later, see UiBuilder::finish()
     fn with_event_bind_to_target<E: Event, T: Component>(
         &mut self,
-        target: Entity,
+        target: impl Into<UiEntityRef>,
         handler: impl Fn(&mut Commands, &E, Mut<T>) + 'static + Send + Sync,
     ) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(EventBindToTarget::<E, T> {
-                target,
-                handler: Box::new(handler),
-            });
+        self.insert_last_with_ref(target, move |target| {
+            EventBindToTarget::<E, T>::new(target, handler)
+        });
+        self.register_binding::<EventBindToTarget<E, T>>();
+        self
+    }
+
+    /// when event E happen, call handler function of bind to modify
+    /// component `T` of target entities, e.g. every entity with marker
+    /// component, with event filter or target entity carried by event
+    fn with_event_bind<E: Event, T: Component>(
+        &mut self,
+        bind: EventBindToTarget<E, T>,
+    ) -> &mut Self {
+        self.insert_last(bind);
+        self.register_binding::<EventBindToTarget<E, T>>();
         self
     }
 
     /// when remote entity `source` component `S` change, call handler function
     ///
-    /// app.register_bind_data_source::<S>() is needed
+    /// `source` can be unique name declared later, see UiBuilder::finish()
     fn with_on_source_change<S: Component>(
         &mut self,
-        source: Entity,
+        source: impl Into<UiEntityRef>,
         handler: impl Fn(&mut Commands, &S) + 'static + Send + Sync,
     ) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(OnSourceChange::<S> {
-                source,
-                handler: Box::new(handler),
-            });
+        self.insert_last_with_ref(source, move |source| OnSourceChange::<S> {
+            source,
+            handler: Box::new(handler),
+        });
+        self.register_binding::<OnSourceChange<S>>();
         self
     }
 
     /// when current entity component `S` change, call handler function to modify
     /// current entity component `T`
-    ///
-    /// app.register_bind_component::<S, T>() is needed
     fn with_self_bind<S: Component, T: Component>(
         &mut self,
         handler: impl Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync,
     ) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(SelfBind::<S, T>(Box::new(handler)));
+        self.insert_last(SelfBind::<S, T>(Box::new(handler)));
+        self.register_binding::<SelfBind<S, T>>();
         self
     }
 
     /// when current entity component `S` change, call handler function to modify
     /// remote entity `target` component `T`
     ///
-    /// app.register_bind_component::<S, T>() is needed
+    /// `target` can be unique name declared later, see UiBuilder::finish()
     fn with_bind_to_target<S: Component, T: Component>(
         &mut self,
-        target: Entity,
+        target: impl Into<UiEntityRef>,
         handler: impl Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync,
     ) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(BindToTarget::<S, T>(vec![BindToTargetItem {
+        self.insert_last_with_ref(target, move |target| {
+            BindToTarget::<S, T>(vec![BindToTargetItem {
                 target,
                 handler: Box::new(handler),
-            }]));
+            }])
+        });
+        self.register_binding::<BindToTarget<S, T>>();
         self
     }
 
     /// when current entity component `S` change, call handler function to modify
     /// remote entity `target` component `T`
-    ///
-    /// app.register_bind_component::<S, T>() is needed
     fn with_bind_to_multiple_targets<S: Component, T: Component>(
         &mut self,
         binds: Vec<BindToTargetItem<S, T>>,
     ) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(BindToTarget::<S, T>(binds));
+        self.insert_last(BindToTarget::<S, T>(binds));
+        self.register_binding::<BindToTarget<S, T>>();
         self
     }
 
     /// when remote entity `source` component `S` change, call handler function to
     /// modify current entity component `T`
     ///
-    /// app.register_bind_component::<S, T>() is needed
+    /// `source` can be unique name declared later, see UiBuilder::finish()
     fn with_bind_source<S: Component, T: Component>(
         &mut self,
-        source: Entity,
+        source: impl Into<UiEntityRef>,
         handler: impl Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync,
     ) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(BindSource::<S, T> {
-                source_entity: source,
-                handler: Box::new(handler),
-            });
+        self.insert_last_with_ref(source, move |source_entity| BindSource::<S, T> {
+            source_entity,
+            handler: Box::new(handler),
+        });
+        self.register_binding::<BindSource<S, T>>();
         self
     }
-}
-
-pub trait AppBindExt {
-    fn register_bind_component<S: Component, T: Component>(&mut self) -> &mut Self;
-    fn register_bind_event<E: Event, T: Component>(&mut self) -> &mut Self;
-    fn register_bind_data_source<S: Component>(&mut self, with_ui: bool) -> &mut Self;
-}
 
-impl AppBindExt for App {
-    /// register bind (S, T), when S change, can effect T
-    fn register_bind_component<S: Component, T: Component>(&mut self) -> &mut Self {
-        self.add_system(self_bind_system::<S, T>);
-        self.add_system(bind_to_target_system::<S, T>);
-        self.add_system(bind_source_system::<S, T>);
+    /// when remote entity `source` component `S` change, call to_widget to
+    /// modify current entity component `T`, when `T` change, call from_widget
+    /// to modify `S`
+    ///
+    /// `source` can be unique name declared later, see UiBuilder::finish()
+    fn with_two_way_bind<S: Component, T: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        to_widget: impl Fn(&mut Commands, &S, Mut<T>) + 'static + Send + Sync,
+        from_widget: impl Fn(&mut Commands, &T, Mut<S>) + 'static + Send + Sync,
+    ) -> &mut Self {
+        self.insert_last_with_ref(source, move |source| TwoWayBind::<S, T> {
+            source,
+            to_widget: Box::new(to_widget),
+            from_widget: Box::new(from_widget),
+        });
+        self.register_binding::<TwoWayBind<S, T>>();
         self
     }
 
-    /// register bind (E, T), when E event happen, can effect T
-    fn register_bind_event<E: Event, T: Component>(&mut self) -> &mut Self {
-        self.add_event::<E>();
-        self.add_system(event_bind_to_target_system::<E, T>);
+    /// when any of remote entities `sources` component `S1, S2, ...` change,
+    /// call handler function with all sources to modify current entity
+    /// component `T`
+    fn with_bind_sources<S: BindSourceTuple, T: Component>(
+        &mut self,
+        sources: S::Entities,
+        handler: impl for<'s> Fn(&mut Commands, S::Refs<'s>, Mut<T>) + 'static + Send + Sync,
+    ) -> &mut Self {
+        self.insert_last(BindSources::<S, T> {
+            sources,
+            handler: Box::new(handler),
+        });
+        self.register_binding::<BindSources<S, T>>();
         self
     }
 
-    /// register data source component
-    fn register_bind_data_source<S: Component>(&mut self, with_ui: bool) -> &mut Self {
-        self.add_system(on_self_change_system::<S>);
-        self.add_system(on_source_change_system::<S>);
-
-        if with_ui {
-            self.register_bind_component::<S, Style>();
-            self.register_bind_component::<S, BackgroundColor>();
-            self.register_bind_component::<S, FocusPolicy>();
-            self.register_bind_component::<S, Visibility>();
-            self.register_bind_component::<S, ZIndex>();
-            self.register_bind_component::<S, ImageMode>();
-            self.register_bind_component::<S, UiImage>();
-            self.register_bind_component::<S, Text>();
+    /// rate control of last added binding, e.g.
+    /// `.with_bind_source(..).with_bind_rate(BindRate::Throttle(..))`
+    ///
+    /// supported by bindings driven by component or resource change, event and
+    /// two way bindings are not supported
+    fn with_bind_rate(&mut self, rate: BindRate) -> &mut Self {
+        let insert_rate = self
+            .last_binding
+            .filter(|binding| Some(binding.entity) == self.last)
+            .and_then(|binding| binding.insert_rate.map(|insert| (binding.entity, insert)));
+        match insert_rate {
+            Some((entity, insert)) => self
+                .backend
+                .add_command(move |world: &mut World| insert(world, entity, rate)),
+            None => self.report_error(UiBuilderError::NoLastBinding),
         }
         self
     }
+
+    /// when resource `R` change, call handler function to modify current
+    /// entity component `T`
+    fn with_bind_resource<R: Resource, T: Component>(
+        &mut self,
+        handler: impl Fn(&mut Commands, &R, Mut<T>) + 'static + Send + Sync,
+    ) -> &mut Self {
+        self.insert_last(BindResource::<R, T>(Box::new(handler)));
+        self.register_binding::<BindResource<R, T>>();
+        self
+    }
+
+    /// when resource `R` change, call handler function
+    fn with_on_resource_change<R: Resource>(
+        &mut self,
+        handler: impl Fn(&mut Commands, &R) + 'static + Send + Sync,
+    ) -> &mut Self {
+        self.insert_last(OnResourceChange::<R>(Box::new(handler)));
+        self.register_binding::<OnResourceChange<R>>();
+        self
+    }
+
+    /// same as with_on_self_change(), handler runs with `&mut World` when
+    /// commands are applied, source and target are both current entity
+    fn with_on_self_change_world<S: Component>(
+        &mut self,
+        handler: impl Fn(&mut World, BindEntities) + 'static + Send + Sync,
+    ) -> &mut Self {
+        let Some(entity) = self.last_or_report() else {
+            return self;
+        };
+        let handler: WorldHandler<BindEntities> = Arc::new(handler);
+        let entities = BindEntities::new(entity, entity);
+        self.with_on_self_change(move |commands, _: &S| {
+            run_world_handler(commands, &handler, entities)
+        })
+    }
+
+    /// same as with_on_self_change(), handler is a system with any system
+    /// params, system input is `In<BindEntities>`
+    fn with_on_self_change_system<S: Component, P>(
+        &mut self,
+        system: impl IntoSystem<BindEntities, (), P>,
+    ) -> &mut Self {
+        let Some(entity) = self.last_or_report() else {
+            return self;
+        };
+        let system = HandlerSystem::new(system);
+        let entities = BindEntities::new(entity, entity);
+        self.with_on_self_change(move |commands, _: &S| system.run(commands, entities))
+    }
+
+    /// same as with_on_source_change(), handler runs with `&mut World` when
+    /// commands are applied, target is current entity
+    fn with_on_source_change_world<S: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut World, BindEntities) + 'static + Send + Sync,
+    ) -> &mut Self {
+        let handler: WorldHandler<BindEntities> = Arc::new(handler);
+        self.with_on_source_change_handler::<S>(source, move |commands, entities| {
+            run_world_handler(commands, &handler, entities)
+        })
+    }
+
+    /// same as with_on_source_change(), handler is a system with any system
+    /// params, system input is `In<BindEntities>`
+    fn with_on_source_change_system<S: Component, P>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        system: impl IntoSystem<BindEntities, (), P>,
+    ) -> &mut Self {
+        let system = HandlerSystem::new(system);
+        self.with_on_source_change_handler::<S>(source, move |commands, entities| {
+            system.run(commands, entities)
+        })
+    }
+
+    /// same as with_on_resource_change(), handler runs with `&mut World` and
+    /// current entity when commands are applied
+    fn with_on_resource_change_world<R: Resource>(
+        &mut self,
+        handler: impl Fn(&mut World, Entity) + 'static + Send + Sync,
+    ) -> &mut Self {
+        let Some(entity) = self.last_or_report() else {
+            return self;
+        };
+        let handler: WorldHandler<Entity> = Arc::new(handler);
+        self.with_on_resource_change(move |commands, _: &R| {
+            run_world_handler(commands, &handler, entity)
+        })
+    }
+
+    /// same as with_on_resource_change(), handler is a system with any system
+    /// params, system input is `In<Entity>` of current entity
+    fn with_on_resource_change_system<R: Resource, P>(
+        &mut self,
+        system: impl IntoSystem<Entity, (), P>,
+    ) -> &mut Self {
+        let Some(entity) = self.last_or_report() else {
+            return self;
+        };
+        let system = HandlerSystem::new(system);
+        self.with_on_resource_change(move |commands, _: &R| system.run(commands, entity))
+    }
+
+    /// when asset of `handle` is loaded or modified, call handler function to
+    /// modify current entity component `T`
+    fn with_bind_asset<A: Asset, T: Component>(
+        &mut self,
+        handle: Handle<A>,
+        handler: impl Fn(&mut Commands, &A, Mut<T>) + 'static + Send + Sync,
+    ) -> &mut Self {
+        self.insert_last(BindAsset::<A, T> {
+            handle,
+            handler: Box::new(handler),
+        });
+        self.register_binding::<BindAsset<A, T>>();
+        self
+    }
+
+    /// same as with_on_self_change(), handler can read a clone of current context
+    fn with_on_self_change_ctx<S: Component>(
+        &mut self,
+        handler: impl Fn(&mut Commands, &S, &C) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        let context = self.context.clone();
+        self.with_on_self_change(move |commands, s: &S| handler(commands, s, &context))
+    }
+
+    /// same as with_event_bind_to_target(), handler can read a clone of current
+    /// context
+    fn with_event_bind_to_target_ctx<E: Event, T: Component>(
+        &mut self,
+        target: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, &E, Mut<T>, &C) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        let context = self.context.clone();
+        self.with_event_bind_to_target(target, move |commands, e: &E, t: Mut<T>| {
+            handler(commands, e, t, &context)
+        })
+    }
+
+    /// same as with_on_source_change(), handler can read a clone of current
+    /// context
+    fn with_on_source_change_ctx<S: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, &S, &C) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        let context = self.context.clone();
+        self.with_on_source_change(source, move |commands, s: &S| {
+            handler(commands, s, &context)
+        })
+    }
+
+    /// same as with_self_bind(), handler can read a clone of current context
+    fn with_self_bind_ctx<S: Component, T: Component>(
+        &mut self,
+        handler: impl Fn(&mut Commands, &S, Mut<T>, &C) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        let context = self.context.clone();
+        self.with_self_bind(move |commands, s: &S, t: Mut<T>| handler(commands, s, t, &context))
+    }
+
+    /// same as with_bind_to_target(), handler can read a clone of current context
+    fn with_bind_to_target_ctx<S: Component, T: Component>(
+        &mut self,
+        target: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, &S, Mut<T>, &C) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        let context = self.context.clone();
+        self.with_bind_to_target(target, move |commands, s: &S, t: Mut<T>| {
+            handler(commands, s, t, &context)
+        })
+    }
+
+    /// same as with_bind_source(), handler can read a clone of current context
+    fn with_bind_source_ctx<S: Component, T: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, &S, Mut<T>, &C) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        let context = self.context.clone();
+        self.with_bind_source(source, move |commands, s: &S, t: Mut<T>| {
+            handler(commands, s, t, &context)
+        })
+    }
+
+    /// keep children of current entity in sync with items of remote entity
+    /// `source` component `S`
+    ///
+    /// items are diffed by key, only children of added, removed or moved items
+    /// are spawned, despawned or reordered, children of kept items are not
+    /// rebuilt, items with the same key are matched in order. build_item_fn
+    /// should create one root node per item, otherwise ListItemRoots error is
+    /// reported and item is skipped. builder has a clone of current context
+    /// and default text style. children of current entity should be created by
+    /// this binding only.
+    fn with_list_binding<S: Component, Item: 'static, Key: Eq + Hash + Send + Sync + 'static>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        items_fn: impl Fn(&S) -> &[Item] + 'static + Send + Sync,
+        key_fn: impl Fn(&Item) -> Key + 'static + Send + Sync,
+        build_item_fn: impl Fn(&mut UiBuilder<Commands, C>, &Item) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        let Some(list_entity) = self.get_last() else {
+            self.report_error(UiBuilderError::NoLastEntity);
+            return self;
+        };
+        let settings = BindingBuilderSettings::new(self);
+        let children = Mutex::new(Vec::<(Key, Entity)>::new());
+        self.with_on_source_change(source, move |commands, s: &S| {
+            let mut children = children.lock().unwrap();
+            let old_entities: Vec<Entity> = children.iter().map(|(_, e)| *e).collect();
+            // items with duplicated key are matched in order
+            let mut old: HashMap<Key, Vec<Entity>> = HashMap::default();
+            for (key, e) in children.drain(..).rev() {
+                old.entry(key).or_default().push(e);
+            }
+            let mut spawned = Vec::new();
+
+            let mut b = settings.builder(commands);
+            for item in items_fn(s) {
+                let key = key_fn(item);
+                let entity = match old.get_mut(&key).and_then(|v| v.pop()) {
+                    Some(e) => e,
+                    None => {
+                        b.last = None;
+                        build_item_fn(&mut b, item);
+                        let Some(e) = take_item_root(&mut b) else {
+                            continue;
+                        };
+                        spawned.push(e);
+                        e
+                    }
+                };
+                children.push((key, entity));
+            }
+            if let Err(errors) = b.finish() {
+                for err in errors {
+                    error!("list binding: {}", err);
+                }
+            }
+            drop(b);
+
+            for e in old.drain().flat_map(|(_, v)| v) {
+                commands.entity(e).despawn_recursive();
+            }
+            let new_entities: Vec<Entity> = children.iter().map(|(_, e)| *e).collect();
+            reorder_list_children(
+                commands,
+                list_entity,
+                &old_entities,
+                &new_entities,
+                &spawned,
+            );
+        })
+    }
+
+    /// when remote entity `source` component `ObservableVec<Item>` change,
+    /// call handler function with operations since last call to modify current
+    /// entity component `T`
+    ///
+    /// first call, and call after operations are lost (e.g. vec replaced),
+    /// receives `[ListOp::Reset]`
+    fn with_bind_list_ops<Item: Send + Sync + 'static, T: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        handler: impl Fn(&mut Commands, &ObservableVec<Item>, &[ListOp], Mut<T>) + 'static + Send + Sync,
+    ) -> &mut Self {
+        self.register_observable_vec::<Item>();
+        let cursor = Mutex::new(ListOpsCursor::default());
+        self.with_bind_source(
+            source,
+            move |commands, items: &ObservableVec<Item>, t: Mut<T>| {
+                let ops = items.read_ops(&mut cursor.lock().unwrap());
+                if !ops.is_empty() {
+                    handler(commands, items, ops, t);
+                }
+            },
+        )
+    }
+
+    /// same as with_list_binding(), but source is `ObservableVec<Item>`, its
+    /// operations are applied to children of current entity, only children of
+    /// inserted or updated items are built, no key needed
+    fn with_observable_list_binding<Item: Send + Sync + 'static>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        build_item_fn: impl Fn(&mut UiBuilder<Commands, C>, &Item) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        let Some(list_entity) = self.get_last() else {
+            self.report_error(UiBuilderError::NoLastEntity);
+            return self;
+        };
+        self.register_observable_vec::<Item>();
+        let settings = BindingBuilderSettings::new(self);
+        let state = Mutex::new((ListOpsCursor::default(), Vec::<Option<Entity>>::new()));
+        self.with_on_source_change(source, move |commands, items: &ObservableVec<Item>| {
+            let mut state = state.lock().unwrap();
+            let (cursor, children) = &mut *state;
+            let ops = items.read_ops(cursor);
+            if ops.is_empty() {
+                return;
+            }
+
+            // (child, needs build)
+            let mut slots: Vec<(Option<Entity>, bool)> =
+                children.iter().map(|e| (*e, false)).collect();
+            let mut removed = Vec::new();
+            let mut valid = true;
+            for op in ops {
+                match *op {
+                    ListOp::Insert(i) if i <= slots.len() => slots.insert(i, (None, true)),
+                    ListOp::Remove(i) if i < slots.len() => removed.extend(slots.remove(i).0),
+                    ListOp::Move { from, to } if from < slots.len() && to < slots.len() => {
+                        let slot = slots.remove(from);
+                        slots.insert(to, slot);
+                    }
+                    ListOp::Update(i) if i < slots.len() => {
+                        removed.extend(slots[i].0.take());
+                        slots[i].1 = true;
+                    }
+                    _ => {
+                        valid = false;
+                        break;
+                    }
+                }
+            }
+            if !valid || slots.len() != items.len() {
+                removed = children.iter().flatten().copied().collect();
+                slots = items.iter().map(|_| (None, true)).collect();
+            }
+
+            let old_entities: Vec<Entity> = children.iter().flatten().copied().collect();
+            let mut spawned = Vec::new();
+            let mut b = settings.builder(commands);
+            for (slot, item) in slots.iter_mut().zip(items.iter()) {
+                if slot.1 {
+                    b.last = None;
+                    build_item_fn(&mut b, item);
+                    slot.0 = take_item_root(&mut b);
+                    spawned.extend(slot.0);
+                }
+            }
+            if let Err(errors) = b.finish() {
+                for err in errors {
+                    error!("observable list binding: {}", err);
+                }
+            }
+            drop(b);
+
+            for e in removed {
+                commands.entity(e).despawn_recursive();
+            }
+            *children = slots.into_iter().map(|(e, _)| e).collect();
+            let new_entities: Vec<Entity> = children.iter().flatten().copied().collect();
+            let old_entities: Vec<Entity> = old_entities
+                .into_iter()
+                .filter(|e| new_entities.contains(e))
+                .collect();
+            reorder_list_children(
+                commands,
+                list_entity,
+                &old_entities,
+                &new_entities,
+                &spawned,
+            );
+        })
+    }
+
+    /// when remote entity `source` component `S` change, build children of
+    /// current entity with build_fn if predicate becomes true, despawn them if
+    /// predicate becomes false
+    ///
+    /// only children built by build_fn are despawned, other children of
+    /// current entity are kept
+    fn with_if<S: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        predicate: impl Fn(&S) -> bool + 'static + Send + Sync,
+        build_fn: impl Fn(&mut UiBuilder<Commands, C>) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        with_if_mode(self, source, false, predicate, build_fn)
+    }
+
+    /// same as with_if(), but children are built once when predicate becomes
+    /// true first time, then current entity Visibility is toggled by predicate
+    ///
+    /// current entity is invisible until predicate becomes true
+    fn with_if_visible<S: Component>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        predicate: impl Fn(&S) -> bool + 'static + Send + Sync,
+        build_fn: impl Fn(&mut UiBuilder<Commands, C>) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        self.with_visibility(false);
+        with_if_mode(self, source, true, predicate, build_fn)
+    }
+}
+
+/// settings of builders created by list and conditional bindings, copied from
+/// builder adding the binding
+struct BindingBuilderSettings<C> {
+    context: C,
+    text_style: TextStyle,
+    name_scope: String,
+    collect_errors: bool,
+}
+
+impl<C: Clone> BindingBuilderSettings<C> {
+    fn new<B: UiBuilderBackend>(b: &UiBuilder<B, C>) -> Self {
+        Self {
+            context: b.context.clone(),
+            text_style: b.default_text_style.clone(),
+            name_scope: b.name_scope.clone(),
+            collect_errors: b.collect_errors,
+        }
+    }
+
+    /// builder runs after names of builder adding the binding are registered,
+    /// so they are found in UiNames
+    fn builder<'a, 'w, 's>(
+        &self,
+        commands: &'a mut Commands<'w, 's>,
+    ) -> UiBuilder<'a, Commands<'w, 's>, C> {
+        let mut b = UiBuilder::new(commands, self.context.clone());
+        b.set_default_text_style(self.text_style.clone())
+            .set_resolve_from_ui_names(true)
+            .set_collect_errors(self.collect_errors);
+        b.name_scope = self.name_scope.clone();
+        b
+    }
+}
+
+/// take root node built for a list item, item with no or more than one root
+/// node is reported and its nodes are despawned
+fn take_item_root<C>(b: &mut UiBuilder<Commands, C>) -> Option<Entity> {
+    let roots = std::mem::take(&mut b.roots);
+    if let [root] = roots[..] {
+        return Some(root);
+    }
+    for e in roots.iter() {
+        b.backend.entity(*e).despawn_recursive();
+    }
+    b.report_error(UiBuilderError::ListItemRoots(roots.len()));
+    None
+}
+
+/// set children of list entity to `new_entities`, only spawned entities are
+/// pushed if kept entities keep their order
+fn reorder_list_children(
+    commands: &mut Commands,
+    list_entity: Entity,
+    old_entities: &[Entity],
+    new_entities: &[Entity],
+    spawned: &[Entity],
+) {
+    let kept: Vec<Entity> = new_entities
+        .iter()
+        .filter(|e| !spawned.contains(e))
+        .copied()
+        .collect();
+    let kept_in_old_order = old_entities.iter().filter(|e| kept.contains(e));
+    let appended = new_entities.ends_with(spawned);
+    if appended && kept_in_old_order.eq(kept.iter()) {
+        if !spawned.is_empty() {
+            commands.entity(list_entity).push_children(spawned);
+        }
+    } else {
+        commands
+            .entity(list_entity)
+            .remove_children(&kept)
+            .push_children(new_entities);
+    }
+}
+
+#[derive(Default)]
+struct ConditionalState {
+    shown: bool,
+    built: bool,
+    /// nodes created by build_fn, other children of entity are kept
+    roots: Vec<Entity>,
+}
+
+fn with_if_mode<'b, 'a, B: UiBuilderBackend, C: Clone + Send + Sync + 'static, S: Component>(
+    b: &'b mut UiBuilder<'a, B, C>,
+    source: impl Into<UiEntityRef>,
+    toggle_visibility: bool,
+    predicate: impl Fn(&S) -> bool + 'static + Send + Sync,
+    build_fn: impl Fn(&mut UiBuilder<Commands, C>) + 'static + Send + Sync,
+) -> &'b mut UiBuilder<'a, B, C> {
+    let Some(parent) = b.get_last() else {
+        b.report_error(UiBuilderError::NoLastEntity);
+        return b;
+    };
+    let settings = BindingBuilderSettings::new(b);
+    let state = Mutex::new(ConditionalState::default());
+    b.with_on_source_change(source, move |commands, s: &S| {
+        let value = predicate(s);
+        let mut state = state.lock().unwrap();
+        if state.shown == value {
+            return;
+        }
+        state.shown = value;
+
+        if value && !state.built {
+            let mut b = settings.builder(commands);
+            b.set_parent(parent);
+            build_fn(&mut b);
+            if let Err(errors) = b.finish() {
+                for err in errors {
+                    error!("conditional binding: {}", err);
+                }
+            }
+            state.roots = std::mem::take(&mut b.roots);
+            state.built = toggle_visibility;
+        }
+        if toggle_visibility {
+            commands
+                .entity(parent)
+                .insert(Visibility { is_visible: value });
+        } else if !value {
+            for root in std::mem::take(&mut state.roots) {
+                commands.entity(root).despawn_recursive();
+            }
+        }
+    })
+}
+
+/// binding component with systems in UiBindStage, systems are added by
+/// `app.register_*()`, or on demand when UiBuilder adds the binding
+pub trait RegisterBinding: Component {
+    /// system of binding supports BindRateLimit<Self>
+    const RATE_LIMITED: bool = false;
+
+    fn register(stage: &mut BindPropagationStage, world: &mut World);
+}
+
+impl<S: Component> RegisterBinding for OnSelfChange<S> {
+    const RATE_LIMITED: bool = true;
+
+    fn register(stage: &mut BindPropagationStage, _world: &mut World) {
+        stage.add_handler_system(on_self_change_system::<S>);
+    }
+}
+
+impl<S: Component> RegisterBinding for OnSourceChange<S> {
+    const RATE_LIMITED: bool = true;
+
+    fn register(stage: &mut BindPropagationStage, world: &mut World) {
+        stage.add_binding_index::<Self>(world);
+        stage.add_handler_system(on_source_change_system::<S>);
+        stage.add_cleanup_system::<Self>();
+    }
+}
+
+impl<E: Event, T: Component> RegisterBinding for EventBindToTarget<E, T> {
+    fn register(stage: &mut BindPropagationStage, world: &mut World) {
+        if !world.contains_resource::<Events<E>>() {
+            warn!(
+                "event {} not added, bindings do nothing until app.register_bind_event::<E, T>() or app.add_event::<E>()",
+                type_name::<E>()
+            );
+        }
+        stage.add_handler_system(event_bind_to_target_system::<E, T>);
+        stage.add_cleanup_system::<Self>();
+    }
+}
+
+impl<S: Component, T: Component> RegisterBinding for SelfBind<S, T> {
+    const RATE_LIMITED: bool = true;
+
+    fn register(stage: &mut BindPropagationStage, _world: &mut World) {
+        stage.add_handler_system(self_bind_system::<S, T>);
+    }
+}
+
+impl<S: Component, T: Component> RegisterBinding for BindToTarget<S, T> {
+    const RATE_LIMITED: bool = true;
+
+    fn register(stage: &mut BindPropagationStage, _world: &mut World) {
+        stage.add_handler_system(bind_to_target_system::<S, T>);
+        stage.add_cleanup_system::<Self>();
+    }
+}
+
+impl<S: Component, T: Component> RegisterBinding for BindSource<S, T> {
+    const RATE_LIMITED: bool = true;
+
+    fn register(stage: &mut BindPropagationStage, world: &mut World) {
+        stage.add_binding_index::<Self>(world);
+        stage.add_handler_system(bind_source_system::<S, T>);
+        stage.add_cleanup_system::<Self>();
+    }
+}
+
+impl<S: Component, T: Component> RegisterBinding for TwoWayBind<S, T> {
+    fn register(stage: &mut BindPropagationStage, _world: &mut World) {
+        stage.add_handler_system(two_way_bind_system::<S, T>);
+        stage.add_cleanup_system::<Self>();
+    }
+}
+
+impl<R: Resource, T: Component> RegisterBinding for BindResource<R, T> {
+    const RATE_LIMITED: bool = true;
+
+    fn register(stage: &mut BindPropagationStage, _world: &mut World) {
+        stage.add_handler_system(bind_resource_system::<R, T>);
+    }
+}
+
+impl<R: Resource> RegisterBinding for OnResourceChange<R> {
+    const RATE_LIMITED: bool = true;
+
+    fn register(stage: &mut BindPropagationStage, _world: &mut World) {
+        stage.add_handler_system(on_resource_change_system::<R>);
+    }
+}
+
+impl<A: Asset, T: Component> RegisterBinding for BindAsset<A, T> {
+    const RATE_LIMITED: bool = true;
+
+    fn register(stage: &mut BindPropagationStage, world: &mut World) {
+        if !world.contains_resource::<Assets<A>>() {
+            warn!(
+                "asset {} not added, bindings do nothing until app.add_asset::<A>()",
+                type_name::<A>()
+            );
+        }
+        stage.add_handler_system(bind_asset_system::<A, T>);
+    }
+}
+
+impl<S: BindSourceTuple, T: Component> RegisterBinding for BindSources<S, T> {
+    const RATE_LIMITED: bool = true;
+
+    fn register(stage: &mut BindPropagationStage, _world: &mut World) {
+        S::register::<T>(stage);
+    }
+}
+
+/// bindings added by UiBuilder, registered at next run of UiBindStage
+#[derive(Resource, Default)]
+pub struct PendingBindRegistrations(pub Vec<(TypeId, RegisterBindingFn)>);
+
+/// RegisterBinding::register of a binding type
+pub type RegisterBindingFn = fn(&mut BindPropagationStage, &mut World);
+
+impl<'a, B: UiBuilderBackend, C> UiBuilder<'a, B, C> {
+    /// register systems of binding `R` on demand, see RegisterBinding
+    pub fn register_binding<R: RegisterBinding>(&mut self) {
+        if let Some(entity) = self.last {
+            self.last_binding = Some(UiLastBinding {
+                entity,
+                insert_rate: R::RATE_LIMITED.then_some(insert_bind_rate::<R> as _),
+            });
+        }
+        self.add_pending_registration(TypeId::of::<R>(), BindPropagationStage::register::<R>);
+    }
+
+    /// register op log trimming of `ObservableVec<T>` on demand, called by
+    /// list op bindings
+    pub fn register_observable_vec<T: Send + Sync + 'static>(&mut self) {
+        self.add_pending_registration(
+            TypeId::of::<ObservableVec<T>>(),
+            register_observable_vec_trim::<T>,
+        );
+    }
+
+    fn add_pending_registration(&mut self, type_id: TypeId, register: RegisterBindingFn) {
+        self.backend.add_command(move |world: &mut World| {
+            world
+                .get_resource_or_insert_with(PendingBindRegistrations::default)
+                .0
+                .push((type_id, register));
+        });
+    }
+}
+
+pub trait AppBindExt {
+    fn register_bind_component<S: Component, T: Component>(&mut self) -> &mut Self;
+    fn register_bind_event<E: Event, T: Component>(&mut self) -> &mut Self;
+    fn register_bind_data_source<S: Component>(&mut self, with_ui: bool) -> &mut Self;
+    fn register_bind_resource<R: Resource, T: Component>(&mut self) -> &mut Self;
+    fn register_bind_sources<S: BindSourceTuple, T: Component>(&mut self) -> &mut Self;
+    fn register_bind_asset<A: Asset, T: Component>(&mut self) -> &mut Self;
+    fn register_binding<R: RegisterBinding>(&mut self) -> &mut Self;
+}
+
+/// stage of bind systems, runs after CoreStage::Update, so changes made by
+/// button_system and game systems are seen in the same frame
+#[derive(Debug, Clone, PartialEq, Eq, Hash, StageLabel)]
+pub struct UiBindStage;
+
+/// stage of cleanup systems added to UiBindStage, runs before CoreStage::Last
+/// where removal events are cleared, so removals of the whole frame are seen
+#[derive(Debug, Clone, PartialEq, Eq, Hash, StageLabel)]
+pub struct UiBindCleanupStage;
+
+/// labels of systems in UiBindStage
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemLabel)]
+pub enum UiBindSystem {
+    /// BindingIndex update
+    Index,
+    /// binding handlers, after Index
+    Bind,
+    /// removal of bindings to despawned entities, in UiBindCleanupStage
+    Cleanup,
+}
+
+/// chained propagation of UiBindStage, e.g. Inventory -> Item -> Text
+#[derive(Resource)]
+pub struct BindPropagation {
+    /// max runs of UiBindStage in one frame
+    pub max_iterations: usize,
+    fired: AtomicBool,
+    frame: u64,
+}
+
+impl Default for BindPropagation {
+    fn default() -> Self {
+        Self {
+            max_iterations: 8,
+            fired: AtomicBool::new(false),
+            frame: 0,
+        }
+    }
+}
+
+impl BindPropagation {
+    /// called by bind systems after any handler is called, UiBindStage runs
+    /// again to propagate changes made by handlers
+    pub fn mark_fired(&self) {
+        self.fired.store(true, Ordering::Relaxed);
+    }
+
+    /// count of UiBindStage runs, increased once per frame
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    fn take_fired(&self) -> bool {
+        self.fired.swap(false, Ordering::Relaxed)
+    }
+}
+
+/// runs bind systems again until no handler is called, or
+/// BindPropagation::max_iterations reached
+pub struct BindPropagationStage {
+    pub stage: SystemStage,
+    /// bindings with systems added
+    pub registered: HashSet<TypeId>,
+    /// cleanup systems, run by BindCleanupStage
+    cleanup: Arc<Mutex<SystemStage>>,
+}
+
+/// keeps UiBindSystem labels defined before any binding is registered, so
+/// ordering against them never refers to unknown labels
+fn bind_label_system() {}
+
+impl Default for BindPropagationStage {
+    fn default() -> Self {
+        Self {
+            stage: SystemStage::parallel()
+                .with_system(bind_label_system.label(UiBindSystem::Index))
+                .with_system(
+                    bind_label_system
+                        .label(UiBindSystem::Bind)
+                        .after(UiBindSystem::Index),
+                ),
+            registered: HashSet::default(),
+            cleanup: Arc::new(Mutex::new(SystemStage::parallel())),
+        }
+    }
+}
+
+impl BindPropagationStage {
+    /// add systems of binding `R` if not added yet
+    pub fn register<R: RegisterBinding>(&mut self, world: &mut World) {
+        if self.registered.insert(TypeId::of::<R>()) {
+            R::register(self, world);
+        }
+    }
+
+    /// add binding handler system, labeled UiBindSystem::Bind
+    pub fn add_handler_system<Params>(&mut self, system: impl IntoSystemDescriptor<Params>) {
+        self.stage
+            .add_system(system.label(UiBindSystem::Bind).after(UiBindSystem::Index));
+    }
+
+    /// add exclusive handler system, labeled UiBindSystem::Bind, it runs at
+    /// start of every propagation iteration, before parallel systems
+    pub fn add_exclusive_handler_system<Params>(
+        &mut self,
+        system: impl IntoSystemDescriptor<Params>,
+    ) {
+        self.stage.add_system(system.label(UiBindSystem::Bind));
+    }
+
+    /// add BindingIndex<B> resource and its update systems
+    pub fn add_binding_index<B: IndexedBinding>(&mut self, world: &mut World) {
+        world.init_resource::<BindingIndex<B>>();
+        self.stage
+            .add_system(binding_index_system::<B>.label(UiBindSystem::Index));
+        self.add_cleanup(binding_index_cleanup_system::<B>);
+    }
+
+    /// add system removing bindings to despawned entities
+    pub fn add_cleanup_system<B: RemoteBinding>(&mut self) {
+        self.add_cleanup(binding_cleanup_system::<B>);
+    }
+
+    /// add cleanup system to UiBindCleanupStage, labeled UiBindSystem::Cleanup,
+    /// it runs once per frame
+    pub fn add_cleanup<Params>(&mut self, system: impl IntoSystemDescriptor<Params>) {
+        self.cleanup
+            .lock()
+            .unwrap()
+            .add_system(system.label(UiBindSystem::Cleanup));
+    }
+}
+
+impl Stage for BindPropagationStage {
+    fn run(&mut self, world: &mut World) {
+        if let Some(mut pending) = world.get_resource_mut::<PendingBindRegistrations>() {
+            for (type_id, register) in std::mem::take(&mut pending.0) {
+                if !self.registered.contains(&type_id) {
+                    register(self, world);
+                }
+            }
+        }
+
+        let mut propagation = world.resource_mut::<BindPropagation>();
+        propagation.frame += 1;
+        propagation.take_fired();
+        let max_iterations = propagation.max_iterations.max(1);
+        for _ in 0..max_iterations {
+            self.stage.run(world);
+            if !world.resource::<BindPropagation>().take_fired() {
+                return;
+            }
+        }
+        warn!(
+            "bindings not settled after {} iterations, check binding cycles or raise BindPropagation::max_iterations",
+            max_iterations
+        );
+    }
+}
+
+/// runs cleanup systems added by BindPropagationStage::add_cleanup()
+pub struct BindCleanupStage(Arc<Mutex<SystemStage>>);
+
+impl Stage for BindCleanupStage {
+    fn run(&mut self, world: &mut World) {
+        self.0.lock().unwrap().run(world);
+    }
+}
+
+/// add UiBindStage and its resources if not added yet
+pub fn add_bind_stage(app: &mut App) {
+    if app
+        .schedule
+        .get_stage::<BindPropagationStage>(UiBindStage)
+        .is_some()
+    {
+        return;
+    }
+    app.init_resource::<BindPropagation>()
+        .init_resource::<BindingDiagnostics>()
+        .init_resource::<PendingBindRegistrations>()
+        .add_event::<BindingBroken>();
+    let stage = BindPropagationStage::default();
+    let cleanup = BindCleanupStage(stage.cleanup.clone());
+    app.add_stage_after(CoreStage::Update, UiBindStage, stage)
+        .add_stage_before(CoreStage::Last, UiBindCleanupStage, cleanup);
+}
+
+impl AppBindExt for App {
+    /// register bind (S, T), when S change, can effect T
+    fn register_bind_component<S: Component, T: Component>(&mut self) -> &mut Self {
+        self.register_binding::<SelfBind<S, T>>()
+            .register_binding::<BindToTarget<S, T>>()
+            .register_binding::<BindSource<S, T>>()
+            .register_binding::<TwoWayBind<S, T>>()
+    }
+
+    /// register bind (E, T), when E event happen, can effect T
+    fn register_bind_event<E: Event, T: Component>(&mut self) -> &mut Self {
+        self.add_event::<E>()
+            .register_binding::<EventBindToTarget<E, T>>()
+    }
+
+    /// register data source component
+    ///
+    /// with_ui is ignored, bindings to ui components are registered when
+    /// UiBuilder adds them. components inserted without UiBuilder need
+    /// register_bind_component::<S, T>()
+    fn register_bind_data_source<S: Component>(&mut self, _with_ui: bool) -> &mut Self {
+        self.register_binding::<OnSelfChange<S>>()
+            .register_binding::<OnSourceChange<S>>()
+    }
+
+    /// register bind (R, T), when resource R change, can effect T
+    fn register_bind_resource<R: Resource, T: Component>(&mut self) -> &mut Self {
+        self.register_binding::<BindResource<R, T>>()
+            .register_binding::<OnResourceChange<R>>()
+    }
+
+    /// register bind ((S1, S2, ...), T), when any of S1, S2, ... change, can
+    /// effect T
+    fn register_bind_sources<S: BindSourceTuple, T: Component>(&mut self) -> &mut Self {
+        self.register_binding::<BindSources<S, T>>()
+    }
+
+    /// register bind (A, T), when asset A is loaded or modified, can effect T
+    fn register_bind_asset<A: Asset, T: Component>(&mut self) -> &mut Self {
+        self.register_binding::<BindAsset<A, T>>()
+    }
+
+    /// add systems of binding `R`, e.g. `BindSource<S, T>`, if not added yet
+    fn register_binding<R: RegisterBinding>(&mut self) -> &mut Self {
+        add_bind_stage(self);
+        let stage = self
+            .schedule
+            .get_stage_mut::<BindPropagationStage>(UiBindStage)
+            .unwrap();
+        stage.register::<R>(&mut self.world);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UiBuilderPlugin;
+    use bevy::reflect::TypeUuid;
+
+    #[derive(Component)]
+    struct Items(Vec<u32>);
+
+    #[derive(Component)]
+    struct Item(u32);
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(bevy::input::InputPlugin)
+            .add_plugin(UiBuilderPlugin);
+        app
+    }
+
+    fn children(app: &App, list: Entity) -> Vec<Entity> {
+        app.world
+            .get::<Children>(list)
+            .map_or(vec![], |children| children.to_vec())
+    }
+
+    fn child_items(app: &App, list: Entity) -> Vec<u32> {
+        children(app, list)
+            .iter()
+            .map(|e| app.world.get::<Item>(*e).unwrap().0)
+            .collect()
+    }
+
+    fn item_count(app: &mut App) -> usize {
+        app.world.query::<&Item>().iter(&app.world).count()
+    }
+
+    fn spawn_list(app: &mut App, items: Vec<u32>) -> (Entity, Entity) {
+        let source = app.world.spawn(Items(items)).id();
+        let mut b = UiBuilder::new(&mut app.world, ());
+        b.node().with_list_binding(
+            source,
+            |items: &Items| &items.0,
+            |item| *item,
+            |b, item| {
+                b.node().with_component(Item(*item));
+            },
+        );
+        let list = b.last();
+        b.finish().unwrap();
+        (source, list)
+    }
+
+    #[test]
+    fn list_binding_reorder_insert_remove_clear() {
+        let mut app = test_app();
+        let (source, list) = spawn_list(&mut app, vec![1, 2, 3]);
+        app.update();
+        assert_eq!(child_items(&app, list), vec![1, 2, 3]);
+        let old = children(&app, list);
+
+        app.world.get_mut::<Items>(source).unwrap().0 = vec![3, 1, 2];
+        app.update();
+        assert_eq!(child_items(&app, list), vec![3, 1, 2]);
+        assert_eq!(children(&app, list), vec![old[2], old[0], old[1]]);
+
+        app.world.get_mut::<Items>(source).unwrap().0 = vec![3, 4, 1, 2];
+        app.update();
+        assert_eq!(child_items(&app, list), vec![3, 4, 1, 2]);
+
+        app.world.get_mut::<Items>(source).unwrap().0 = vec![4, 2];
+        app.update();
+        assert_eq!(child_items(&app, list), vec![4, 2]);
+        assert_eq!(item_count(&mut app), 2);
+
+        app.world.get_mut::<Items>(source).unwrap().0 = vec![];
+        app.update();
+        assert!(children(&app, list).is_empty());
+        assert_eq!(item_count(&mut app), 0);
+    }
+
+    #[test]
+    fn list_binding_duplicated_keys() {
+        let mut app = test_app();
+        let (source, list) = spawn_list(&mut app, vec![1, 1]);
+        app.update();
+        assert_eq!(child_items(&app, list), vec![1, 1]);
+
+        app.world.get_mut::<Items>(source).unwrap().0 = vec![];
+        app.update();
+        assert_eq!(item_count(&mut app), 0);
+    }
+
+    #[derive(Component)]
+    struct Source(u32);
+
+    #[derive(Component)]
+    struct Widget(u32);
+
+    #[test]
+    fn two_way_bind_does_not_ping_pong() {
+        use std::sync::atomic::AtomicUsize;
+
+        let mut app = test_app();
+        let source = app.world.spawn(Source(1)).id();
+        let to_widget_calls = Arc::new(AtomicUsize::new(0));
+        let from_widget_calls = Arc::new(AtomicUsize::new(0));
+        let (to_calls, from_calls) = (to_widget_calls.clone(), from_widget_calls.clone());
+        let widget = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node().with_component(Widget(0)).with_two_way_bind(
+                source,
+                move |_, s: &Source, mut w: Mut<Widget>| {
+                    to_calls.fetch_add(1, Ordering::Relaxed);
+                    w.0 = s.0;
+                },
+                move |_, w: &Widget, mut s: Mut<Source>| {
+                    from_calls.fetch_add(1, Ordering::Relaxed);
+                    s.0 = w.0;
+                },
+            );
+            let widget = b.last();
+            b.finish().unwrap();
+            widget
+        };
+        let calls = || {
+            (
+                to_widget_calls.load(Ordering::Relaxed),
+                from_widget_calls.load(Ordering::Relaxed),
+            )
+        };
+
+        // initial sync from source, placeholder is not written back
+        app.update();
+        app.update();
+        assert_eq!(app.world.get::<Widget>(widget).unwrap().0, 1);
+        assert_eq!(calls(), (1, 0));
+
+        app.world.get_mut::<Source>(source).unwrap().0 = 2;
+        app.update();
+        app.update();
+        assert_eq!(app.world.get::<Widget>(widget).unwrap().0, 2);
+        assert_eq!(calls(), (2, 0));
+
+        app.world.get_mut::<Widget>(widget).unwrap().0 = 3;
+        app.update();
+        app.update();
+        assert_eq!(app.world.get::<Source>(source).unwrap().0, 3);
+        assert_eq!(calls(), (2, 1));
+    }
+
+    #[test]
+    fn bind_chain_propagates_in_same_frame() {
+        let mut app = test_app();
+        let a = app.world.spawn(Source(1)).id();
+        let (b_node, c_node) = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Widget(0))
+                .with_bind_source(a, |_, s: &Source, mut w: Mut<Widget>| w.0 = s.0);
+            let b_node = b.last();
+            b.node()
+                .with_component(Item(0))
+                .with_bind_source(b_node, |_, w: &Widget, mut i: Mut<Item>| i.0 = w.0);
+            let c_node = b.last();
+            b.finish().unwrap();
+            (b_node, c_node)
+        };
+        app.update();
+        assert_eq!(app.world.get::<Item>(c_node).unwrap().0, 1);
+
+        app.world.get_mut::<Source>(a).unwrap().0 = 2;
+        app.update();
+        assert_eq!(app.world.get::<Widget>(b_node).unwrap().0, 2);
+        assert_eq!(app.world.get::<Item>(c_node).unwrap().0, 2);
+    }
+
+    #[test]
+    fn observable_list_binding_applies_ops() {
+        let mut app = test_app();
+        let source = app.world.spawn(ObservableVec::from(vec![1u32, 2, 3])).id();
+        let list = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_observable_list_binding(source, |b, item: &u32| {
+                    b.node().with_component(Item(*item));
+                });
+            let list = b.last();
+            b.finish().unwrap();
+            list
+        };
+        let modify = |app: &mut App, f: fn(&mut ObservableVec<u32>)| {
+            f(&mut app.world.get_mut::<ObservableVec<u32>>(source).unwrap());
+            app.update();
+        };
+
+        app.update();
+        assert_eq!(child_items(&app, list), vec![1, 2, 3]);
+        let e = children(&app, list);
+
+        // moved entities are kept
+        modify(&mut app, |v| v.move_item(0, 2));
+        assert_eq!(child_items(&app, list), vec![2, 3, 1]);
+        assert_eq!(children(&app, list), vec![e[1], e[2], e[0]]);
+
+        // only updated and inserted items are built
+        modify(&mut app, |v| {
+            v.set(1, 30);
+            v.push(4);
+            v.remove(0);
+        });
+        assert_eq!(child_items(&app, list), vec![30, 1, 4]);
+        let after_update = children(&app, list);
+        assert!(!e.contains(&after_update[0]));
+        assert_eq!(after_update[1], e[0]);
+        assert_eq!(item_count(&mut app), 3);
+
+        // replace rebuilds everything
+        modify(&mut app, |v| v.replace(vec![7, 8]));
+        assert_eq!(child_items(&app, list), vec![7, 8]);
+        assert!(children(&app, list)
+            .iter()
+            .all(|e| !after_update.contains(e)));
+        assert_eq!(item_count(&mut app), 2);
+
+        modify(&mut app, |v| v.clear());
+        assert!(children(&app, list).is_empty());
+        assert_eq!(item_count(&mut app), 0);
+    }
+
+    #[test]
+    fn forward_ref_resolved_by_finish() {
+        let mut app = test_app();
+        let target = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Widget(0))
+                .with_bind_source("source", |_, s: &Source, mut w: Mut<Widget>| w.0 = s.0);
+            let target = b.last();
+            b.node()
+                .with_unique_name("source")
+                .with_component(Source(5));
+            b.finish().unwrap();
+            target
+        };
+        app.update();
+        assert_eq!(app.world.get::<Widget>(target).unwrap().0, 5);
+
+        let mut b = UiBuilder::new(&mut app.world, ());
+        b.set_collect_errors(true);
+        b.node()
+            .with_component(Widget(0))
+            .with_bind_source("missing", |_, s: &Source, mut w: Mut<Widget>| w.0 = s.0);
+        let errors = b.finish().unwrap_err();
+        assert!(matches!(&errors[..], [UiBuilderError::NameNotFound(name)] if name == "missing"));
+    }
+
+    #[test]
+    fn dropped_builder_does_not_panic_on_forward_ref() {
+        let mut app = test_app();
+        {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Widget(0))
+                .with_bind_source("missing", |_, s: &Source, mut w: Mut<Widget>| w.0 = s.0);
+        }
+        app.update();
+    }
+
+    #[test]
+    fn list_item_resolves_name_from_ui_names() {
+        let mut app = test_app();
+        let source = app.world.spawn(Items(vec![1, 2])).id();
+        let list = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node().with_list_binding(
+                source,
+                |items: &Items| &items.0,
+                |item| *item,
+                |b, item| {
+                    let item = *item;
+                    b.node()
+                        .with_component(Item(0))
+                        .with_bind_source("offset", move |_, s: &Source, mut i: Mut<Item>| {
+                            i.0 = s.0 + item
+                        });
+                },
+            );
+            let list = b.last();
+            b.node()
+                .with_unique_name("offset")
+                .with_component(Source(10));
+            b.finish().unwrap();
+            list
+        };
+        app.update();
+        app.update();
+        assert_eq!(child_items(&app, list), vec![11, 12]);
+    }
+
+    #[derive(Component)]
+    struct DespawnInPostUpdate;
+
+    fn despawn_in_post_update(
+        mut commands: Commands,
+        query: Query<Entity, With<DespawnInPostUpdate>>,
+    ) {
+        for e in query.iter() {
+            commands.entity(e).despawn();
+        }
+    }
+
+    fn broken_events(app: &App) -> Vec<BindingBroken> {
+        let events = app.world.resource::<Events<BindingBroken>>();
+        events.get_reader().iter(events).cloned().collect()
+    }
+
+    #[test]
+    fn cleanup_after_despawn_in_post_update() {
+        let mut app = test_app();
+        app.add_system_to_stage(CoreStage::PostUpdate, despawn_in_post_update);
+        let source = app.world.spawn(Source(1)).id();
+        let target = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Widget(0))
+                .with_bind_source(source, |_, s: &Source, mut w: Mut<Widget>| w.0 = s.0);
+            let target = b.last();
+            b.finish().unwrap();
+            target
+        };
+        app.update();
+        assert_eq!(
+            app.world
+                .resource::<BindingIndex<BindSource<Source, Widget>>>()
+                .get(source),
+            &[target]
+        );
+
+        // despawned after UiBindStage, seen by UiBindCleanupStage
+        app.world.entity_mut(source).insert(DespawnInPostUpdate);
+        app.update();
+        assert_eq!(
+            broken_events(&app),
+            vec![BindingBroken {
+                binding_entity: target,
+                remote_entity: source,
+                binding_type: type_name::<BindSource<Source, Widget>>(),
+                remote_type: type_name::<Source>(),
+            }]
+        );
+        app.update();
+        assert!(app
+            .world
+            .get::<BindSource<Source, Widget>>(target)
+            .is_none());
+        assert!(app
+            .world
+            .resource::<BindingIndex<BindSource<Source, Widget>>>()
+            .iter()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn cleanup_bind_to_target_keeps_live_targets() {
+        let mut app = test_app();
+        let targets = [
+            app.world.spawn(Widget(0)).id(),
+            app.world.spawn(Widget(0)).id(),
+        ];
+        let source = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Source(1))
+                .with_bind_to_multiple_targets(
+                    targets
+                        .iter()
+                        .map(|target| BindToTargetItem {
+                            target: *target,
+                            handler: Box::new(|_, s: &Source, mut w: Mut<Widget>| w.0 = s.0),
+                        })
+                        .collect(),
+                );
+            let source = b.last();
+            b.finish().unwrap();
+            source
+        };
+        app.update();
+        app.world.despawn(targets[0]);
+        app.update();
+        assert_eq!(broken_events(&app).len(), 1);
+
+        app.world.get_mut::<Source>(source).unwrap().0 = 2;
+        app.update();
+        let binding = app
+            .world
+            .get::<BindToTarget<Source, Widget>>(source)
+            .unwrap();
+        assert_eq!(binding.remotes().as_slice(), &targets[1..]);
+        assert_eq!(app.world.get::<Widget>(targets[1]).unwrap().0, 2);
+    }
+
+    #[test]
+    fn removed_binding_leaves_index() {
+        let mut app = test_app();
+        let source = app.world.spawn(Source(1)).id();
+        let target = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Widget(0))
+                .with_on_source_change(source, |_, _: &Source| {});
+            let target = b.last();
+            b.finish().unwrap();
+            target
+        };
+        app.update();
+        let index = |app: &App| {
+            app.world
+                .resource::<BindingIndex<OnSourceChange<Source>>>()
+                .get(source)
+                .to_vec()
+        };
+        assert_eq!(index(&app), vec![target]);
+
+        app.world
+            .entity_mut(target)
+            .remove::<OnSourceChange<Source>>();
+        app.update();
+        assert!(index(&app).is_empty());
+    }
+
+    #[test]
+    fn with_if_keeps_other_children() {
+        let mut app = test_app();
+        let source = app.world.spawn(Source(0)).id();
+        let node = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_if(
+                    source,
+                    |s: &Source| s.0 > 0,
+                    |b| {
+                        b.node().with_component(Item(1));
+                        b.node().with_component(Item(2)).with_children(|b| {
+                            b.node().with_component(Item(3));
+                        });
+                    },
+                )
+                .with_children(|b| {
+                    b.node();
+                });
+            let node = b.last();
+            b.finish().unwrap();
+            node
+        };
+        app.update();
+        let kept = children(&app, node);
+        assert_eq!(kept.len(), 1);
+
+        app.world.get_mut::<Source>(source).unwrap().0 = 1;
+        app.update();
+        assert_eq!(children(&app, node).len(), 3);
+        assert_eq!(item_count(&mut app), 3);
+
+        app.world.get_mut::<Source>(source).unwrap().0 = 0;
+        app.update();
+        assert_eq!(children(&app, node), kept);
+        assert_eq!(item_count(&mut app), 0);
+
+        // built again
+        app.world.get_mut::<Source>(source).unwrap().0 = 2;
+        app.update();
+        assert_eq!(item_count(&mut app), 3);
+    }
+
+    #[test]
+    fn with_if_visible_builds_once() {
+        let mut app = test_app();
+        let source = app.world.spawn(Source(1)).id();
+        let node = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node().with_if_visible(
+                source,
+                |s: &Source| s.0 > 0,
+                |b| {
+                    b.node().with_component(Item(1));
+                },
+            );
+            let node = b.last();
+            b.finish().unwrap();
+            node
+        };
+        let visible = |app: &App| app.world.get::<Visibility>(node).unwrap().is_visible;
+        app.update();
+        assert!(visible(&app));
+        assert_eq!(item_count(&mut app), 1);
+
+        app.world.get_mut::<Source>(source).unwrap().0 = 0;
+        app.update();
+        assert!(!visible(&app));
+        assert_eq!(item_count(&mut app), 1);
+
+        app.world.get_mut::<Source>(source).unwrap().0 = 1;
+        app.update();
+        assert!(visible(&app));
+        assert_eq!(item_count(&mut app), 1);
+    }
+
+    #[test]
+    fn list_item_with_multiple_roots_is_skipped() {
+        let mut app = test_app();
+        let source = app.world.spawn(Items(vec![1, 2])).id();
+        let list = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.set_collect_errors(true);
+            b.node().with_list_binding(
+                source,
+                |items: &Items| &items.0,
+                |item| *item,
+                |b, item| {
+                    b.node().with_component(Item(*item));
+                    if *item == 2 {
+                        b.node().with_component(Item(20));
+                    }
+                },
+            );
+            let list = b.last();
+            b.finish().unwrap();
+            list
+        };
+        app.update();
+        assert_eq!(child_items(&app, list), vec![1]);
+        assert_eq!(item_count(&mut app), 1);
+    }
+
+    #[test]
+    fn ctx_handler_receives_context() {
+        let mut app = test_app();
+        let source = app.world.spawn(Source(1)).id();
+        let (outer, inner) = {
+            let mut b = UiBuilder::new(&mut app.world, 10u32);
+            b.node()
+                .with_component(Widget(0))
+                .with_bind_source_ctx(source, |_, s: &Source, mut w: Mut<Widget>, ctx: &u32| {
+                    w.0 = s.0 + ctx
+                });
+            let outer = b.last();
+            b.with_context(100u32, |b| {
+                b.node().with_component(Widget(0)).with_bind_source_ctx(
+                    source,
+                    |_, s: &Source, mut w: Mut<Widget>, ctx: &u32| w.0 = s.0 + ctx,
+                );
+            });
+            let inner = b.last();
+            assert_eq!(*b.context(), 10);
+            b.finish().unwrap();
+            (outer, inner)
+        };
+        app.update();
+        assert_eq!(app.world.get::<Widget>(outer).unwrap().0, 11);
+        assert_eq!(app.world.get::<Widget>(inner).unwrap().0, 101);
+    }
+
+    #[derive(Resource)]
+    struct Level(u32);
+
+    #[test]
+    fn resource_bindings_wait_for_insert_and_follow_changes() {
+        use std::sync::atomic::AtomicU32;
+
+        let mut app = test_app();
+        let seen = Arc::new(AtomicU32::new(0));
+        let node = {
+            let seen = seen.clone();
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Widget(0))
+                .with_bind_resource(|_, level: &Level, mut w: Mut<Widget>| w.0 = level.0)
+                .with_on_resource_change(move |_, level: &Level| {
+                    seen.store(level.0, Ordering::Relaxed)
+                });
+            let node = b.last();
+            b.finish().unwrap();
+            node
+        };
+        app.update();
+        assert_eq!(app.world.get::<Widget>(node).unwrap().0, 0);
+
+        app.insert_resource(Level(1));
+        app.update();
+        assert_eq!(app.world.get::<Widget>(node).unwrap().0, 1);
+        assert_eq!(seen.load(Ordering::Relaxed), 1);
+
+        app.world.resource_mut::<Level>().0 = 2;
+        app.update();
+        assert_eq!(app.world.get::<Widget>(node).unwrap().0, 2);
+        assert_eq!(seen.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn on_resource_change_registered_manually() {
+        use std::sync::atomic::AtomicU32;
+
+        let mut app = test_app();
+        app.register_binding::<OnResourceChange<Level>>()
+            .insert_resource(Level(3));
+        let seen = Arc::new(AtomicU32::new(0));
+        let handler_seen = seen.clone();
+        app.world
+            .spawn(OnResourceChange::<Level>(Box::new(move |_, level| {
+                handler_seen.store(level.0, Ordering::Relaxed)
+            })));
+        app.update();
+        assert_eq!(seen.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn list_ops_binding_registers_op_log_trim() {
+        let mut app = test_app();
+        let source = app.world.spawn(ObservableVec::from(vec![1u32])).id();
+        let node = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node().with_component(Widget(0)).with_bind_list_ops(
+                source,
+                |_, _: &ObservableVec<u32>, ops: &[ListOp], mut w: Mut<Widget>| {
+                    w.0 += ops.len() as u32
+                },
+            );
+            let node = b.last();
+            b.finish().unwrap();
+            node
+        };
+        app.update();
+        assert_eq!(app.world.get::<Widget>(node).unwrap().0, 1);
+
+        let mut vec = app.world.get_mut::<ObservableVec<u32>>(source).unwrap();
+        vec.push(2);
+        vec.push(3);
+        app.update();
+        assert_eq!(app.world.get::<Widget>(node).unwrap().0, 3);
+
+        // ops read in previous frame are trimmed
+        app.update();
+        let vec = app.world.get::<ObservableVec<u32>>(source).unwrap();
+        assert!(vec.ops().is_empty());
+    }
+
+    fn registered<R: 'static>(app: &App) -> bool {
+        app.schedule
+            .get_stage::<BindPropagationStage>(UiBindStage)
+            .unwrap()
+            .registered
+            .contains(&TypeId::of::<R>())
+    }
+
+    #[test]
+    fn bindings_registered_on_demand() {
+        let mut app = test_app();
+        app.register_bind_data_source::<Source>(true);
+        assert!(registered::<OnSourceChange<Source>>(&app));
+        assert!(!registered::<BindSource<Source, Text>>(&app));
+
+        let source = app.world.spawn(Source(1)).id();
+        let node = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.text("")
+                .with_bind_source(source, |_, s: &Source, mut text: Mut<Text>| {
+                    text.sections[0].value = s.0.to_string()
+                });
+            let node = b.last();
+            b.finish().unwrap();
+            node
+        };
+        app.update();
+        assert!(registered::<BindSource<Source, Text>>(&app));
+        assert_eq!(app.world.get::<Text>(node).unwrap().sections[0].value, "1");
+    }
+
+    #[test]
+    fn bind_sources_combines_sources() {
+        let mut app = test_app();
+        let a = app.world.spawn(Source(2)).id();
+        let b_node = app.world.spawn(Widget(3)).id();
+        let node = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Item(0))
+                .with_bind_sources::<(Source, Widget), Item>((a, b_node), |_, (s, w), mut i| {
+                    i.0 = s.0 * w.0
+                });
+            let node = b.last();
+            b.finish().unwrap();
+            node
+        };
+        app.update();
+        assert_eq!(app.world.get::<Item>(node).unwrap().0, 6);
+
+        app.world.get_mut::<Widget>(b_node).unwrap().0 = 4;
+        app.update();
+        assert_eq!(app.world.get::<Item>(node).unwrap().0, 8);
+
+        app.world.despawn(a);
+        app.update();
+        assert_eq!(
+            broken_events(&app),
+            vec![BindingBroken {
+                binding_entity: node,
+                remote_entity: a,
+                binding_type: type_name::<BindSources<(Source, Widget), Item>>(),
+                remote_type: type_name::<Source>(),
+            }]
+        );
+        assert!(app
+            .world
+            .get::<BindSources<(Source, Widget), Item>>(node)
+            .is_none());
+    }
+
+    #[test]
+    fn bindings_sync_once_when_added() {
+        use std::sync::atomic::AtomicUsize;
+
+        let mut app = test_app();
+        let source = app.world.spawn(Source(1)).id();
+        let other = app.world.spawn(Source(5)).id();
+        // sources are not changed in the frame bindings are added
+        app.update();
+        app.update();
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let on_change_calls = calls.clone();
+        let (bind_source, self_bind, target) = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Widget(0))
+                .with_bind_source(source, |_, s: &Source, mut w: Mut<Widget>| w.0 = s.0)
+                .with_on_source_change(source, move |_, _: &Source| {
+                    on_change_calls.fetch_add(1, Ordering::Relaxed);
+                });
+            let bind_source = b.last();
+            b.node()
+                .with_component(Source(2))
+                .with_component(Widget(0))
+                .with_self_bind(|_, s: &Source, mut w: Mut<Widget>| w.0 = s.0);
+            let self_bind = b.last();
+            b.node().with_component(Item(0));
+            let target = b.last();
+            b.node()
+                .with_component(Source(3))
+                .with_bind_to_target(target, |_, s: &Source, mut i: Mut<Item>| i.0 = s.0);
+            b.finish().unwrap();
+            (bind_source, self_bind, target)
+        };
+        app.update();
+        assert_eq!(app.world.get::<Widget>(bind_source).unwrap().0, 1);
+        assert_eq!(app.world.get::<Widget>(self_bind).unwrap().0, 2);
+        assert_eq!(app.world.get::<Item>(target).unwrap().0, 3);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        app.update();
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        // changed source entity syncs again
+        app.world
+            .get_mut::<BindSource<Source, Widget>>(bind_source)
+            .unwrap()
+            .source_entity = other;
+        app.update();
+        assert_eq!(app.world.get::<Widget>(bind_source).unwrap().0, 5);
+    }
+
+    #[derive(Component)]
+    struct Slot;
+
+    #[derive(Clone)]
+    struct Refresh {
+        slot: Option<Entity>,
+        amount: u32,
+    }
+
+    /// three named slots with marker and one other item, returns slots
+    fn spawn_slots(app: &mut App, bind: EventBindToTarget<Refresh, Item>) -> Vec<Entity> {
+        app.add_event::<Refresh>();
+        let mut b = UiBuilder::new(&mut app.world, ());
+        let slots = (0..3)
+            .map(|i| {
+                b.node()
+                    .with_name(format!("slot {}", i))
+                    .with_component(Slot)
+                    .with_component(Item(0));
+                b.last()
+            })
+            .collect();
+        b.node().with_name("other").with_component(Item(0));
+        b.node().with_event_bind(bind);
+        b.finish().unwrap();
+        slots
+    }
+
+    fn send_refresh(app: &mut App, slot: Option<Entity>, amount: u32) {
+        app.world.send_event(Refresh { slot, amount });
+        app.update();
+    }
+
+    fn slot_items(app: &mut App) -> Vec<u32> {
+        let mut items: Vec<_> = app
+            .world
+            .query::<(&Name, &Item)>()
+            .iter(&app.world)
+            .map(|(name, item)| (name.to_string(), item.0))
+            .collect();
+        items.sort();
+        items.into_iter().map(|(_, item)| item).collect()
+    }
+
+    #[test]
+    fn event_bind_fans_out_to_marker_with_filter() {
+        let mut app = test_app();
+        spawn_slots(
+            &mut app,
+            EventBindToTarget::new(
+                EventBindTarget::marker::<Slot>(),
+                |_, ev: &Refresh, mut i: Mut<Item>| i.0 += ev.amount,
+            )
+            .with_filter(|ev| ev.amount > 0),
+        );
+        send_refresh(&mut app, None, 1);
+        // other, slot 0, slot 1, slot 2
+        assert_eq!(slot_items(&mut app), vec![0, 1, 1, 1]);
+
+        send_refresh(&mut app, None, 0);
+        assert_eq!(slot_items(&mut app), vec![0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn event_bind_to_event_target() {
+        let mut app = test_app();
+        let slots = spawn_slots(
+            &mut app,
+            EventBindToTarget::new(
+                EventBindTarget::name_prefix("slot"),
+                |_, ev: &Refresh, mut i: Mut<Item>| i.0 += ev.amount,
+            )
+            .with_event_target(|ev| ev.slot),
+        );
+        send_refresh(&mut app, Some(slots[1]), 2);
+        assert_eq!(slot_items(&mut app), vec![0, 0, 2, 0]);
+
+        // events without target entity are skipped
+        send_refresh(&mut app, None, 2);
+        assert_eq!(slot_items(&mut app), vec![0, 0, 2, 0]);
+
+        // event target must be one of binding targets
+        let other = app
+            .world
+            .query::<(Entity, &Name)>()
+            .iter(&app.world)
+            .find(|(_, name)| name.as_str() == "other")
+            .unwrap()
+            .0;
+        send_refresh(&mut app, Some(other), 2);
+        assert_eq!(slot_items(&mut app), vec![0, 0, 2, 0]);
+    }
+
+    #[test]
+    fn bind_rate_limit_update() {
+        let ms = Duration::from_millis;
+
+        let mut throttle = BindRateLimit::<Widget>::new(BindRate::Throttle(ms(100)));
+        assert!(throttle.update(true, 0.0, 0));
+        assert!(!throttle.update(true, 0.05, 1));
+        assert!(!throttle.update(false, 0.08, 2));
+        // last change in interval is handled when interval ends
+        assert!(throttle.update(false, 0.1, 3));
+        assert!(!throttle.update(false, 0.3, 4));
+
+        let mut debounce = BindRateLimit::<Widget>::new(BindRate::Debounce(ms(100)));
+        assert!(!debounce.update(true, 0.0, 0));
+        assert!(!debounce.update(true, 0.08, 1));
+        assert!(!debounce.update(false, 0.15, 2));
+        assert!(debounce.update(false, 0.2, 3));
+        assert!(!debounce.update(false, 0.5, 4));
+
+        let mut every = BindRateLimit::<Widget>::new(BindRate::EveryFrames(2));
+        assert!(every.update(true, 0.0, 0));
+        assert!(!every.update(true, 0.0, 1));
+        assert!(every.update(false, 0.0, 2));
+        assert!(!every.update(false, 0.0, 3));
+    }
+
+    #[test]
+    fn bind_rate_coalesces_changes() {
+        use std::sync::atomic::AtomicUsize;
+
+        let mut app = test_app();
+        let source = app.world.spawn(Source(0)).id();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let handler_calls = calls.clone();
+        let node = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Widget(0))
+                .with_bind_source(source, move |_, s: &Source, mut w: Mut<Widget>| {
+                    handler_calls.fetch_add(1, Ordering::Relaxed);
+                    w.0 = s.0;
+                })
+                .with_bind_rate(BindRate::EveryFrames(3));
+            let node = b.last();
+            b.finish().unwrap();
+            node
+        };
+        for i in 1..=7 {
+            app.world.get_mut::<Source>(source).unwrap().0 = i;
+            app.update();
+        }
+        // frames 1, 4 and 7
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+        assert_eq!(app.world.get::<Widget>(node).unwrap().0, 7);
+    }
+
+    #[test]
+    fn bind_rate_without_binding_is_error() {
+        let mut app = test_app();
+        let mut b = UiBuilder::new(&mut app.world, ());
+        let errors = b
+            .try_build(|b| {
+                b.node().with_bind_rate(BindRate::EveryFrames(2));
+            })
+            .unwrap_err();
+        assert!(matches!(errors[..], [UiBuilderError::NoLastBinding]));
+    }
+
+    #[test]
+    fn world_and_system_handlers() {
+        let mut app = test_app();
+        let source = app.world.spawn(Source(1)).id();
+        let (world_node, system_node) = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node()
+                .with_component(Widget(0))
+                .with_on_source_change_world::<Source>(source, |world, entities| {
+                    let value = world.get::<Source>(entities.source).unwrap().0;
+                    world.get_mut::<Widget>(entities.target).unwrap().0 = value;
+                });
+            let world_node = b.last();
+            b.node()
+                .with_component(Item(0))
+                .with_on_source_change_system::<Source, _>(
+                    source,
+                    |In(entities): In<BindEntities>,
+                     sources: Query<&Source>,
+                     mut items: Query<&mut Item>,
+                     mut runs: Local<u32>| {
+                        *runs += 1;
+                        let value = sources.get(entities.source).unwrap().0;
+                        items.get_mut(entities.target).unwrap().0 = value * 10 + *runs;
+                    },
+                );
+            let system_node = b.last();
+            b.finish().unwrap();
+            (world_node, system_node)
+        };
+        app.update();
+        assert_eq!(app.world.get::<Widget>(world_node).unwrap().0, 1);
+        assert_eq!(app.world.get::<Item>(system_node).unwrap().0, 11);
+
+        // system Local is kept between runs
+        app.world.get_mut::<Source>(source).unwrap().0 = 2;
+        app.update();
+        assert_eq!(app.world.get::<Widget>(world_node).unwrap().0, 2);
+        assert_eq!(app.world.get::<Item>(system_node).unwrap().0, 22);
+    }
+
+    #[derive(TypeUuid)]
+    #[uuid = "5b0f5b8e-3a0c-4a52-9f43-0d3c1f1e6a27"]
+    struct Table(u32);
+
+    fn spawn_asset_bind(app: &mut App, handle: Handle<Table>) -> Entity {
+        let mut b = UiBuilder::new(&mut app.world, ());
+        b.node()
+            .with_component(Widget(0))
+            .with_bind_asset(handle, |_, table: &Table, mut w: Mut<Widget>| w.0 = table.0);
+        let node = b.last();
+        b.finish().unwrap();
+        node
+    }
+
+    fn widget(app: &App, node: Entity) -> u32 {
+        app.world.get::<Widget>(node).unwrap().0
+    }
+
+    #[test]
+    fn bind_asset_follows_asset_events() {
+        let mut app = test_app();
+        app.add_asset::<Table>();
+        let handle = app.world.resource_mut::<Assets<Table>>().add(Table(1));
+        app.update();
+
+        // loaded asset is applied when binding is added
+        let node = spawn_asset_bind(&mut app, handle.clone());
+        app.update();
+        assert_eq!(widget(&app, node), 1);
+
+        // asset events are sent after UiBindStage, handled next frame
+        app.world
+            .resource_mut::<Assets<Table>>()
+            .get_mut(&handle)
+            .unwrap()
+            .0 = 2;
+        app.update();
+        assert_eq!(widget(&app, node), 1);
+        app.update();
+        assert_eq!(widget(&app, node), 2);
+    }
+
+    #[test]
+    fn bind_asset_waits_for_load() {
+        let mut app = test_app();
+        app.add_asset::<Table>();
+        let id = HandleId::random::<Table>();
+        let node = spawn_asset_bind(&mut app, Handle::weak(id));
+        app.update();
+        assert_eq!(widget(&app, node), 0);
+
+        app.world
+            .resource_mut::<Assets<Table>>()
+            .set_untracked(id, Table(5));
+        app.update();
+        app.update();
+        assert_eq!(widget(&app, node), 5);
+    }
 }
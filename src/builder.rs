@@ -1,38 +1,317 @@
-use std::borrow::Cow;
-
-use bevy::{ecs::event::Event, prelude::*, ui::widget::ImageMode, utils::HashMap};
-
-use crate::{buttons::*, modifiers::*};
+use std::{
+    borrow::Cow,
+    sync::{Arc, Mutex},
+};
+
+use bevy::{
+    ecs::{event::Event, system::BoxedSystem},
+    prelude::*,
+    ui::widget::ImageMode,
+    utils::HashMap,
+};
+
+use crate::{
+    backend::UiBuilderBackend, bind::BindRate, buttons::*, error::UiBuilderError, modifiers::*,
+    names::*, widgets::UiWidget,
+};
 
 /// UI Builder
-pub struct UiBuilder<'w, 's, 'a, C> {
-    pub commands: &'a mut Commands<'w, 's>,
+///
+/// backend can be `Commands`, `World` or `ChildBuilder`
+pub struct UiBuilder<'a, B: UiBuilderBackend, C> {
+    pub backend: &'a mut B,
     pub context: C,
     pub parent: Option<Entity>,
     pub last: Option<Entity>,
+    /// nodes created outside with_children(), children of parent if it is set
+    pub roots: Vec<Entity>,
     pub default_text_style: TextStyle,
     pub last_text_content: String,
     pub name_entity_map: HashMap<Cow<'static, str>, Entity>,
+    pub entity_name_map: HashMap<Entity, Cow<'static, str>>,
+    /// path of nearest unique named ancestor, prefix of unique names
+    pub name_scope: String,
+    /// collect errors instead of panic, with_* fn with error is skipped
+    pub collect_errors: bool,
+    /// collected errors, take them with finish() or take_errors()
+    pub errors: Vec<UiBuilderError>,
+    /// bindings referencing unique names not declared yet, resolved by finish()
+    pub forward_refs: Vec<UiForwardRef>,
+    /// names not declared in this builder are looked up in UiNames resource
+    /// when commands are applied, instead of NameNotFound error by finish()
+    pub resolve_from_ui_names: bool,
+    /// last added binding, with_bind_rate() applies to it
+    pub last_binding: Option<UiLastBinding>,
+}
+
+/// forward references left when builder is dropped without finish() are never
+/// resolved, they are logged with `error!`
+impl<'a, B: UiBuilderBackend, C> Drop for UiBuilder<'a, B, C> {
+    fn drop(&mut self) {
+        for forward_ref in std::mem::take(&mut self.forward_refs) {
+            error!(
+                "{}, builder dropped without finish()",
+                UiBuilderError::NameNotFound(forward_ref.name.into_owned())
+            );
+        }
+    }
+}
+
+/// binding added to an entity by UiBuilder
+#[derive(Clone, Copy)]
+pub struct UiLastBinding {
+    pub entity: Entity,
+    /// insert rate control of binding, None if binding does not support it
+    pub insert_rate: Option<fn(&mut World, Entity, BindRate)>,
 }
 
-impl<'w, 's, 'a, C> UiBuilder<'w, 's, 'a, C> {
+/// binding waiting for a unique name to be declared
+pub struct UiForwardRef {
+    /// entity the binding is added to
+    pub owner: Entity,
+    pub name: Cow<'static, str>,
+    /// name scope when the binding is added, name is relative to it
+    pub name_scope: String,
+    /// insert binding component to owner with resolved entity
+    pub insert: UiForwardRefInsert,
+}
+
+/// fn(world, owner, resolved entity)
+pub type UiForwardRefInsert = Box<dyn FnOnce(&mut World, Entity, Entity) + 'static + Send + Sync>;
+
+impl<'a, B: UiBuilderBackend, C> UiBuilder<'a, B, C> {
     /// create ui builder
-    pub fn new(commands: &'a mut Commands<'w, 's>, context: C) -> Self {
+    pub fn new(backend: &'a mut B, context: C) -> Self {
         Self {
-            commands,
+            backend,
             context,
             parent: None,
             last: None,
+            roots: Vec::new(),
             default_text_style: TextStyle::default(),
             last_text_content: String::new(),
             name_entity_map: HashMap::default(),
+            entity_name_map: HashMap::default(),
+            name_scope: String::new(),
+            collect_errors: false,
+            errors: Vec::new(),
+            forward_refs: Vec::new(),
+            resolve_from_ui_names: false,
+            last_binding: None,
+        }
+    }
+
+    /// get context
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// get context as mutable
+    pub fn context_mut(&mut self) -> &mut C {
+        &mut self.context
+    }
+
+    /// replace context
+    pub fn set_context(&mut self, context: C) -> &mut Self {
+        self.context = context;
+        self
+    }
+
+    /// build with another context, old context is back after build_fn
+    ///
+    /// nodes created in build_fn are at same level, last entity is kept
+    pub fn with_context<C2>(
+        &mut self,
+        context: C2,
+        build_fn: impl FnOnce(&mut UiBuilder<'_, B, C2>),
+    ) -> &mut Self {
+        let mut b = UiBuilder {
+            backend: &mut *self.backend,
+            context,
+            parent: self.parent,
+            last: self.last,
+            roots: std::mem::take(&mut self.roots),
+            default_text_style: self.default_text_style.clone(),
+            last_text_content: std::mem::take(&mut self.last_text_content),
+            name_entity_map: std::mem::take(&mut self.name_entity_map),
+            entity_name_map: std::mem::take(&mut self.entity_name_map),
+            name_scope: self.name_scope.clone(),
+            collect_errors: self.collect_errors,
+            errors: std::mem::take(&mut self.errors),
+            forward_refs: std::mem::take(&mut self.forward_refs),
+            resolve_from_ui_names: self.resolve_from_ui_names,
+            last_binding: self.last_binding,
+        };
+        build_fn(&mut b);
+        self.last = b.last;
+        self.roots = std::mem::take(&mut b.roots);
+        self.last_text_content = std::mem::take(&mut b.last_text_content);
+        self.name_entity_map = std::mem::take(&mut b.name_entity_map);
+        self.entity_name_map = std::mem::take(&mut b.entity_name_map);
+        self.errors = std::mem::take(&mut b.errors);
+        self.forward_refs = std::mem::take(&mut b.forward_refs);
+        self.last_binding = b.last_binding;
+        drop(b);
+        self
+    }
+
+    /// collect errors instead of panic, with_* fn with error is skipped,
+    /// useful when ui is built from data
+    pub fn set_collect_errors(&mut self, value: bool) -> &mut Self {
+        self.collect_errors = value;
+        self
+    }
+
+    /// look up names not declared in this builder in UiNames resource when
+    /// commands are applied, e.g. for builders running after the names are
+    /// registered. names never found are logged with `error!`
+    pub fn set_resolve_from_ui_names(&mut self, value: bool) -> &mut Self {
+        self.resolve_from_ui_names = value;
+        self
+    }
+
+    /// run build_fn in collect errors mode, errors reported inside it, e.g.
+    /// by with_* fn without last entity or duplicated unique name, are
+    /// returned instead of panicking. fallible path of every with_* fn
+    ///
+    /// forward references are not resolved yet, their errors are returned by
+    /// finish()
+    pub fn try_build<R>(
+        &mut self,
+        build_fn: impl FnOnce(&mut Self) -> R,
+    ) -> Result<R, Vec<UiBuilderError>> {
+        let old_collect_errors = self.collect_errors;
+        let old_errors = std::mem::take(&mut self.errors);
+        self.collect_errors = true;
+        let result = build_fn(self);
+        self.collect_errors = old_collect_errors;
+        let errors = std::mem::replace(&mut self.errors, old_errors);
+        if errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// take collected errors
+    pub fn take_errors(&mut self) -> Vec<UiBuilderError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// call after building, resolve forward references and take collected
+    /// errors, Ok if there is no error
+    ///
+    /// forward reference with unique name never declared is NameNotFound error
+    pub fn finish(&mut self) -> Result<(), Vec<UiBuilderError>> {
+        self.resolve_forward_refs();
+        let errors = self.take_errors();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// collect error, or panic if not in collect errors mode
+    pub fn report_error(&mut self, error: UiBuilderError) {
+        if self.collect_errors {
+            self.errors.push(error);
+        } else {
+            panic!("{}", error);
+        }
+    }
+
+    /// resolve forward references with unique names declared so far, called
+    /// by finish()
+    pub fn resolve_forward_refs(&mut self) {
+        for forward_ref in std::mem::take(&mut self.forward_refs) {
+            let UiForwardRef {
+                owner,
+                name,
+                name_scope,
+                insert,
+            } = forward_ref;
+            if let Some(target) = self.find_unique_name(&name_scope, &name) {
+                self.backend
+                    .add_command(move |world: &mut World| insert(world, owner, target));
+            } else if self.resolve_from_ui_names {
+                self.backend.add_command(move |world: &mut World| {
+                    let target = world
+                        .get_resource::<UiNames>()
+                        .and_then(|names| names.find(&name_scope, &name));
+                    match target {
+                        Some(target) => insert(world, owner, target),
+                        None => error!("{}", UiBuilderError::NameNotFound(name.into_owned())),
+                    }
+                });
+            } else {
+                self.report_error(UiBuilderError::NameNotFound(name.into_owned()));
+            }
+        }
+    }
+
+    /// insert bundle referencing another entity to last entity
+    ///
+    /// if target is a unique name not declared yet, bundle is inserted when
+    /// the name is resolved by finish()
+    pub(crate) fn insert_last_with_ref<T: Bundle>(
+        &mut self,
+        target: impl Into<UiEntityRef>,
+        bundle_fn: impl FnOnce(Entity) -> T + 'static + Send + Sync,
+    ) {
+        let Some(owner) = self.last_or_report() else {
+            return;
+        };
+        let name = match target.into() {
+            UiEntityRef::Entity(target) => {
+                self.backend.insert(owner, bundle_fn(target));
+                return;
+            }
+            UiEntityRef::Name(name) => name,
+        };
+        if let Some(target) = self.find_unique_name(&self.name_scope, &name) {
+            self.backend.insert(owner, bundle_fn(target));
+            return;
+        }
+        self.forward_refs.push(UiForwardRef {
+            owner,
+            name,
+            name_scope: self.name_scope.clone(),
+            insert: Box::new(move |world, owner, target| {
+                if let Some(mut owner) = world.get_entity_mut(owner) {
+                    owner.insert(bundle_fn(target));
+                }
+            }),
+        });
+    }
+
+    pub(crate) fn last_or_report(&mut self) -> Option<Entity> {
+        match self.try_last() {
+            Ok(e) => Some(e),
+            Err(err) => {
+                self.report_error(err);
+                None
+            }
+        }
+    }
+
+    /// insert bundle to last entity, skipped if there is no last entity in
+    /// collect errors mode
+    pub(crate) fn insert_last(&mut self, bundle: impl Bundle) {
+        if let Some(e) = self.last_or_report() {
+            self.backend.insert(e, bundle);
         }
     }
 
     /// get last created entity
     /// with_* fn will modify last entity
     pub fn last(&self) -> Entity {
-        self.last.expect("no last entity")
+        self.try_last().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// get last created entity, error if no entity created yet
+    pub fn try_last(&self) -> Result<Entity, UiBuilderError> {
+        self.last.ok_or(UiBuilderError::NoLastEntity)
     }
 
     /// get last entity as option
@@ -49,7 +328,12 @@ impl<'w, 's, 'a, C> UiBuilder<'w, 's, 'a, C> {
 
     /// get parent
     pub fn parent(&self) -> Entity {
-        self.parent.expect("no parent entity")
+        self.try_parent().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// get parent, error if outside with_children()
+    pub fn try_parent(&self) -> Result<Entity, UiBuilderError> {
+        self.parent.ok_or(UiBuilderError::NoParentEntity)
     }
 
     /// get parent as option
@@ -65,13 +349,17 @@ impl<'w, 's, 'a, C> UiBuilder<'w, 's, 'a, C> {
 
     /// assign last entity value to param
     pub fn pull_last(&mut self, e: &mut Entity) -> &mut Self {
-        *e = self.last();
+        if let Some(last) = self.last_or_report() {
+            *e = last;
+        }
         self
     }
 
     /// assign last entity value to option param
     pub fn pull_last_as_option(&mut self, e: &mut Option<Entity>) -> &mut Self {
-        *e = Some(self.last());
+        if let Some(last) = self.last_or_report() {
+            *e = Some(last);
+        }
         self
     }
 
@@ -89,145 +377,174 @@ impl<'w, 's, 'a, C> UiBuilder<'w, 's, 'a, C> {
 
     fn put_new_node(&mut self, e: Entity) {
         if let Some(p) = self.parent {
-            self.commands.entity(p).add_child(e);
+            self.backend.add_child(p, e);
         }
+        self.roots.push(e);
         self.last = Some(e);
     }
 
     /// create new node entity (NodeBundle)
     pub fn node(&mut self) -> &mut Self {
-        let e = self.commands.spawn(NodeBundle::default()).id();
+        let e = self.backend.spawn(NodeBundle::default());
         self.put_new_node(e);
         self
     }
 
     /// create new image entity (ImageBundle)
     pub fn image(&mut self, image: Handle<Image>) -> &mut Self {
-        let e = self
-            .commands
-            .spawn(ImageBundle {
-                image: image.into(),
-                ..default()
-            })
-            .id();
+        let e = self.backend.spawn(ImageBundle {
+            image: image.into(),
+            ..default()
+        });
         self.put_new_node(e);
         self
     }
 
     /// change last entity image mode
     pub fn with_image_mode(&mut self, image_mode: ImageMode) -> &mut Self {
-        self.commands.entity(self.last()).insert(image_mode);
+        self.insert_last(image_mode);
         self
     }
 
     /// create new text entity (TextBundle)
     pub fn text(&mut self, text: impl Into<String>) -> &mut Self {
         self.last_text_content = text.into();
-        let e = self
-            .commands
-            .spawn(TextBundle::from_section(
-                self.last_text_content.clone(),
-                self.default_text_style.clone(),
-            ))
-            .id();
+        let e = self.backend.spawn(TextBundle::from_section(
+            self.last_text_content.clone(),
+            self.default_text_style.clone(),
+        ));
         self.put_new_node(e);
         self
     }
 
     /// create base button
     pub fn button(&mut self) -> &mut Self {
-        let e = self
-            .commands
-            .spawn((
-                ButtonBundle::default(),
-                Disabled(false),
-                ButtonVisualState::Normal,
-                ButtonInternalState::default(),
-            ))
-            .id();
+        let e = self.backend.spawn((
+            ButtonBundle::default(),
+            Disabled(false),
+            ButtonVisualState::Normal,
+            ButtonInternalState::default(),
+        ));
         self.put_new_node(e);
         self
     }
 
+    /// create widget, last entity is widget root
+    pub fn widget(&mut self, widget: impl UiWidget<C>) -> &mut Self {
+        widget.build(self);
+        self
+    }
+
     /// button: add click handler
     pub fn with_on_button_click(
         &mut self,
         handler: impl Fn(&mut Commands, &ButtonClickInfo) + 'static + Send + Sync,
     ) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(OnButtonClick(Box::new(handler)));
+        self.insert_last(OnButtonClick(Box::new(handler)));
         self
     }
 
+    /// button: add click handler, handler can read a clone of current context
+    pub fn with_on_button_click_ctx(
+        &mut self,
+        handler: impl Fn(&mut Commands, &ButtonClickInfo, &C) + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        let context = self.context.clone();
+        self.with_on_button_click(move |commands, info| handler(commands, info, &context))
+    }
+
+    /// button: add click handler with `&mut World`, handler runs when
+    /// commands are applied
+    pub fn with_on_button_click_world(
+        &mut self,
+        handler: impl Fn(&mut World, &ButtonClickInfo) + 'static + Send + Sync,
+    ) -> &mut Self {
+        let handler: WorldHandler<ButtonClickInfo> =
+            Arc::new(move |world, info| handler(world, &info));
+        self.with_on_button_click(move |commands, info| {
+            run_world_handler(commands, &handler, info.clone())
+        })
+    }
+
+    /// button: add click handler as system with any system params, system
+    /// input is `In<ButtonClickInfo>`
+    pub fn with_on_button_click_system<P>(
+        &mut self,
+        system: impl IntoSystem<ButtonClickInfo, (), P>,
+    ) -> &mut Self {
+        let system = HandlerSystem::new(system);
+        self.with_on_button_click(move |commands, info| system.run(commands, info.clone()))
+    }
+
     /// button: send event on click
     /// will overwrite other click handler
     pub fn with_send_event_click<E: Event + Clone>(&mut self, e: E) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(OnButtonClick(Box::new(
-                move |commands: &mut Commands, _info: &ButtonClickInfo| {
-                    send_event(commands, e.clone());
-                },
-            )));
+        self.insert_last(OnButtonClick(Box::new(
+            move |commands: &mut Commands, _info: &ButtonClickInfo| {
+                send_event(commands, e.clone());
+            },
+        )));
         self
     }
 
     /// change last button mode to toggle mode
     pub fn with_toggle(&mut self, toggle: bool) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(ToggleButton { toggled: toggle });
+        self.insert_last(ToggleButton { toggled: toggle });
         self
     }
 
     /// toggle mode in specific group, only one button in group can be toggled
     /// only work with toggle mode
     pub fn with_toggle_group(&mut self, group_name: impl Into<String>) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(ToggleButtonGroup(group_name.into()));
+        self.insert_last(ToggleButtonGroup(group_name.into()));
         self
     }
 
     /// change click action on release
     pub fn with_action_on_release(&mut self) -> &mut Self {
-        self.commands.entity(self.last()).insert(ActionOnRelease);
+        self.insert_last(ActionOnRelease);
         self
     }
 
     /// change mouse button mask
     pub fn with_mouse_button_mask(&mut self, mask: &[MouseButton]) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(MouseButtonMask::new(mask));
+        self.insert_last(MouseButtonMask::new(mask));
         self
     }
 
     /// change button images when state changed
     pub fn with_image_button(&mut self, value: impl Into<ImageButton>) -> &mut Self {
-        self.commands.entity(self.last()).insert(value.into());
+        self.insert_last(value.into());
         self
     }
 
     /// change button background color when state changed
     /// use with button()
     pub fn with_color_button(&mut self, value: ColorButton) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert((BackgroundColor(value.normal), value));
+        self.insert_last((BackgroundColor(value.normal), value));
         self
     }
 
     /// create children on last entity
+    /// if last entity has unique name, it is the name scope of children
     pub fn with_children(&mut self, build_fn: impl FnOnce(&mut Self)) -> &mut Self {
         let old_parent = self.parent;
         let old_last = self.last;
+        let old_name_scope = self.name_scope.clone();
+        if let Some(path) = self.last.and_then(|e| self.entity_name_map.get(&e)) {
+            self.name_scope = path.to_string();
+        }
+        let old_roots = std::mem::take(&mut self.roots);
         self.parent = self.last;
         self.last = None;
         build_fn(self);
         self.parent = old_parent;
         self.last = old_last;
+        self.roots = old_roots;
+        self.name_scope = old_name_scope;
         self
     }
 
@@ -235,37 +552,94 @@ impl<'w, 's, 'a, C> UiBuilder<'w, 's, 'a, C> {
     ///
     /// bevy_inspector_egui will show name as entity name, easier to find entity
     pub fn with_name(&mut self, name: impl Into<Cow<'static, str>>) -> &mut Self {
-        self.commands.entity(self.last()).insert(Name::new(name));
+        self.insert_last(Name::new(name));
         self
     }
 
-    /// add Name component
+    /// add Name and UiNamePath component
     /// save entity to name_entity_map for later use get_entity_with_unique_name()
+    /// and register it to UiNames resource
+    ///
+    /// full path is scoped by unique named ancestors, e.g.
+    /// `"settings/audio/volume_slider"`
     pub fn with_unique_name(&mut self, name: impl Into<Cow<'static, str>>) -> &mut Self {
-        let name = name.into();
-        let old = self.name_entity_map.insert(name.clone(), self.last());
-        assert!(old.is_none(), "duplicate name: {}", name);
-        self.commands.entity(self.last()).insert(Name::new(name));
+        if let Err(err) = self.try_with_unique_name(name) {
+            self.report_error(err);
+        }
         self
     }
 
+    /// same as with_unique_name(), error if no last entity or duplicate name
+    pub fn try_with_unique_name(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<&mut Self, UiBuilderError> {
+        let name = name.into();
+        let entity = self.try_last()?;
+        let path: Cow<'static, str> = if self.name_scope.is_empty() {
+            name.clone()
+        } else {
+            ui_name_path(&self.name_scope, &name).into()
+        };
+        if self.name_entity_map.contains_key(&path) {
+            return Err(UiBuilderError::DuplicateName(path.into_owned()));
+        }
+        self.name_entity_map.insert(path.clone(), entity);
+        self.entity_name_map.insert(entity, path.clone());
+        self.backend
+            .insert(entity, (Name::new(name), UiNamePath(path.to_string())));
+        self.backend.add_command(move |world: &mut World| {
+            world
+                .get_resource_or_insert_with(UiNames::default)
+                .insert(path, entity);
+        });
+        Ok(self)
+    }
+
     /// get entity by unique name
+    /// name is path relative to current name scope, or full path
+    ///
+    /// panics if not found, use try_get_entity() to handle missing name
     pub fn get_entity_with_unique_name(&mut self, name: impl Into<Cow<'static, str>>) -> Entity {
+        self.try_get_entity(name)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// get entity by unique name, error if not found
+    pub fn try_get_entity(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Entity, UiBuilderError> {
+        let name = name.into();
+        self.find_unique_name(&self.name_scope, &name)
+            .ok_or_else(|| UiBuilderError::NameNotFound(name.into_owned()))
+    }
+
+    /// find name relative to scope, then full path
+    fn find_unique_name(&self, scope: &str, name: &str) -> Option<Entity> {
+        let relative = ui_name_path(scope, name);
         self.name_entity_map
-            .get(&name.into())
-            .expect("unique name not found")
-            .clone()
+            .get(relative.as_str())
+            .or_else(|| self.name_entity_map.get(name))
+            .copied()
     }
 
     /// insert or overwrite last entity component
     pub fn with_component(&mut self, c: impl Component) -> &mut Self {
-        self.commands.entity(self.last()).insert(c);
+        self.insert_last(c);
         self
     }
 
+    /// same as with_component(), error if no last entity
+    pub fn try_with_component(&mut self, c: impl Component) -> Result<&mut Self, UiBuilderError> {
+        let e = self.try_last()?;
+        self.backend.insert(e, c);
+        Ok(self)
+    }
+
     /// set last entity disabled as true
     pub fn with_disabled(&mut self, value: bool) -> &mut Self {
-        self.commands.entity(self.last()).insert(Disabled(value));
+        self.insert_last(Disabled(value));
         self
     }
 
@@ -274,7 +648,7 @@ impl<'w, 's, 'a, C> UiBuilder<'w, 's, 'a, C> {
     pub fn with_style_modifier(&mut self, style_modifier: impl StyleModifier) -> &mut Self {
         let mut style = Style::default();
         style_modifier.modify(&mut style);
-        self.commands.entity(self.last()).insert(style);
+        self.insert_last(style);
         self
     }
 
@@ -289,14 +663,14 @@ impl<'w, 's, 'a, C> UiBuilder<'w, 's, 'a, C> {
             ..default()
         };
         text_modifier.modify(&mut text);
-        self.commands.entity(self.last()).insert(text);
+        self.insert_last(text);
         self
     }
 
     /// modify last entity visibility (Visibility component)
     /// call this multiple time will overwrite previous state
     pub fn with_visibility(&mut self, visible: bool) -> &mut Self {
-        self.commands.entity(self.last()).insert(Visibility {
+        self.insert_last(Visibility {
             is_visible: visible,
         });
         self
@@ -305,9 +679,7 @@ impl<'w, 's, 'a, C> UiBuilder<'w, 's, 'a, C> {
     /// modify last entity background color (BackgroundColor component)
     /// call this multiple time will overwrite previous color
     pub fn with_bg_color(&mut self, color: Color) -> &mut Self {
-        self.commands
-            .entity(self.last())
-            .insert(BackgroundColor(color));
+        self.insert_last(BackgroundColor(color));
         self
     }
 }
@@ -319,3 +691,158 @@ pub fn send_event<E: Event>(commands: &mut Commands, e: E) {
         events_resource.send(e);
     });
 }
+
+/// system run by handlers as command, handler can use any system params,
+/// e.g. `fn on_click(In(info): In<ButtonClickInfo>, asset_server: Res<AssetServer>)`
+///
+/// system is initialized on first run, its state is kept between runs
+pub struct HandlerSystem<I: 'static>(Arc<Mutex<HandlerSystemState<I>>>);
+
+/// boxed system and whether it is initialized
+type HandlerSystemState<I> = (BoxedSystem<I, ()>, bool);
+
+impl<I: 'static> Clone for HandlerSystem<I> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I: Send + Sync + 'static> HandlerSystem<I> {
+    pub fn new<P>(system: impl IntoSystem<I, (), P>) -> Self {
+        Self(Arc::new(Mutex::new((
+            Box::new(IntoSystem::into_system(system)),
+            false,
+        ))))
+    }
+
+    /// run system with input when commands are applied
+    pub fn run(&self, commands: &mut Commands, input: I) {
+        let system = self.0.clone();
+        commands.add(move |world: &mut World| {
+            let mut system = system.lock().unwrap();
+            let (system, initialized) = &mut *system;
+            if !*initialized {
+                system.initialize(world);
+                *initialized = true;
+            }
+            system.run(input, world);
+            system.apply_buffers(world);
+        });
+    }
+}
+
+/// shared handler with `&mut World`, run by run_world_handler()
+pub type WorldHandler<I> = Arc<dyn Fn(&mut World, I) + Send + Sync>;
+
+/// helper function for running handler with `&mut World` with Commands
+pub fn run_world_handler<I: Send + Sync + 'static>(
+    commands: &mut Commands,
+    handler: &WorldHandler<I>,
+    input: I,
+) {
+    let handler = handler.clone();
+    commands.add(move |world: &mut World| handler(world, input));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_name_lookup() {
+        let mut world = World::new();
+        let mut b = UiBuilder::new(&mut world, ());
+        b.node().with_unique_name("settings").with_children(|b| {
+            b.node().with_unique_name("volume");
+            let volume = b.last();
+            assert_eq!(b.get_entity_with_unique_name("volume"), volume);
+            assert_eq!(b.get_entity_with_unique_name("settings/volume"), volume);
+        });
+        assert!(matches!(
+            b.try_get_entity("volume"),
+            Err(UiBuilderError::NameNotFound(name)) if name == "volume"
+        ));
+        b.finish().unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_unique_name_panics() {
+        let mut world = World::new();
+        let mut b = UiBuilder::new(&mut world, ());
+        b.get_entity_with_unique_name("missing");
+    }
+
+    #[test]
+    fn try_build_returns_errors() {
+        let mut world = World::new();
+        let mut b = UiBuilder::new(&mut world, ());
+        let errors = b
+            .try_build(|b| {
+                b.with_name("orphan");
+                b.node().with_unique_name("a");
+                b.node().with_unique_name("a");
+            })
+            .unwrap_err();
+        assert!(matches!(
+            &errors[..],
+            [UiBuilderError::NoLastEntity, UiBuilderError::DuplicateName(name)] if name == "a"
+        ));
+        assert!(!b.collect_errors);
+        assert_eq!(b.try_build(|b| b.node().last()).ok(), b.get_last());
+        b.finish().unwrap();
+    }
+
+    #[test]
+    fn roots_exclude_children() {
+        let mut world = World::new();
+        let mut b = UiBuilder::new(&mut world, ());
+        b.node().with_children(|b| {
+            b.node();
+        });
+        let first = b.last();
+        b.with_context(1, |b| {
+            b.node();
+        });
+        let second = b.last();
+        assert_eq!(b.roots, vec![first, second]);
+        b.finish().unwrap();
+    }
+
+    #[derive(Resource, Default)]
+    struct Clicks(Vec<Entity>);
+
+    #[test]
+    fn world_and_system_click_handlers() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(bevy::input::InputPlugin)
+            .add_plugin(crate::UiBuilderPlugin)
+            .init_resource::<Clicks>();
+        let (world_button, system_button) = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.button().with_on_button_click_world(|world, info| {
+                world.resource_mut::<Clicks>().0.push(info.entity);
+            });
+            let world_button = b.last();
+            b.button().with_on_button_click_system(
+                |In(info): In<ButtonClickInfo>, mut clicks: ResMut<Clicks>| {
+                    clicks.0.push(info.entity);
+                },
+            );
+            let system_button = b.last();
+            b.finish().unwrap();
+            (world_button, system_button)
+        };
+        app.update();
+        for button in [world_button, system_button] {
+            *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::Clicked;
+            app.update();
+        }
+        assert_eq!(
+            app.world.resource::<Clicks>().0,
+            vec![world_button, system_button]
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+/// error of fallible `try_*` UiBuilder functions, also collected by UiBuilder
+/// when `set_collect_errors(true)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UiBuilderError {
+    /// with_* fn called before any node created
+    NoLastEntity,
+    /// parent() called outside with_children()
+    NoParentEntity,
+    /// with_unique_name() called with a path already used
+    DuplicateName(String),
+    /// unique name not found
+    NameNotFound(String),
+    /// with_bind_rate() called without binding supporting it on last entity
+    NoLastBinding,
+    /// list item builder created this number of root nodes instead of one
+    ListItemRoots(usize),
+}
+
+impl fmt::Display for UiBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UiBuilderError::NoLastEntity => write!(f, "no last entity"),
+            UiBuilderError::NoParentEntity => write!(f, "no parent entity"),
+            UiBuilderError::DuplicateName(name) => write!(f, "duplicate name: {}", name),
+            UiBuilderError::NameNotFound(name) => write!(f, "unique name not found: {}", name),
+            UiBuilderError::NoLastBinding => write!(f, "no rate controlled binding on last entity"),
+            UiBuilderError::ListItemRoots(count) => {
+                write!(f, "list item built {} root nodes instead of one", count)
+            }
+        }
+    }
+}
+
+impl std::error::Error for UiBuilderError {}
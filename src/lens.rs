@@ -0,0 +1,874 @@
+use std::{any::type_name, marker::PhantomData, sync::Arc};
+
+use bevy::{
+    ecs::{
+        entity::{EntityMap, MapEntities, MapEntitiesError},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+    reflect::{GetPath, TypeRegistryInternal},
+    utils::{HashMap, HashSet},
+};
+use smallvec::{smallvec, SmallVec};
+
+use crate::{
+    backend::UiBuilderBackend,
+    bind::{
+        send_binding_broken_world, AppBindExt, BindPropagation, BindPropagationStage,
+        BindingBroken, BindingIndex, IndexedBinding, NewBindings, RegisterBinding, RemoteBinding,
+    },
+    builder::UiBuilder,
+    names::UiEntityRef,
+};
+
+/// when remote entity `source` component `S` change, copy field at
+/// `source_path` to current entity component `T` field at `target_path`, also
+/// copied once when added or `source` changed
+///
+/// closure free, can be reflected, saved in scene and shown in inspector.
+/// fields are converted by convert_reflect(), or named converter from
+/// LensConverters
+///
+/// paths are reflect paths, e.g. `"items[0].1"`, `"sections[0].value"`
+///
+/// app.register_lens_bind::<S, T>() is needed
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct LensBind<S: Component + Reflect, T: Component + Reflect> {
+    pub source: Entity,
+    pub source_path: String,
+    pub target_path: String,
+    /// name of converter in LensConverters
+    pub converter: Option<String>,
+    #[reflect(ignore)]
+    pub marker: PhantomData<fn() -> (S, T)>,
+}
+
+impl<S: Component + Reflect, T: Component + Reflect> LensBind<S, T> {
+    pub fn new(
+        source: Entity,
+        source_path: impl Into<String>,
+        target_path: impl Into<String>,
+    ) -> Self {
+        Self {
+            source,
+            source_path: source_path.into(),
+            target_path: target_path.into(),
+            converter: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// use named converter from LensConverters
+    pub fn with_converter(mut self, converter: impl Into<String>) -> Self {
+        self.converter = Some(converter.into());
+        self
+    }
+}
+
+impl<S: Component + Reflect, T: Component + Reflect> FromWorld for LensBind<S, T> {
+    fn from_world(_world: &mut World) -> Self {
+        Self::new(Entity::from_raw(u32::MAX), "", "")
+    }
+}
+
+impl<S: Component + Reflect, T: Component + Reflect> MapEntities for LensBind<S, T> {
+    fn map_entities(&mut self, entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
+        if let Ok(mapped_entity) = entity_map.get(self.source) {
+            self.source = mapped_entity;
+        }
+        Ok(())
+    }
+}
+
+impl<S: Component + Reflect, T: Component + Reflect> IndexedBinding for LensBind<S, T> {
+    fn source(&self) -> Entity {
+        self.source
+    }
+}
+
+impl<S: Component + Reflect, T: Component + Reflect> RemoteBinding for LensBind<S, T> {
+    type Remote = S;
+
+    fn remotes(&self) -> SmallVec<[Entity; 1]> {
+        smallvec![self.source]
+    }
+
+    fn remove_remote(&mut self, _remote: Entity) -> bool {
+        true
+    }
+}
+
+/// fn(source field, target field), return false if value can not be converted
+pub type LensConverter =
+    Box<dyn Fn(&dyn Reflect, &mut dyn Reflect) -> bool + 'static + Send + Sync>;
+
+/// named converters of lens bindings
+#[derive(Resource, Default)]
+pub struct LensConverters {
+    converters: HashMap<String, LensConverter>,
+}
+
+impl LensConverters {
+    /// get converter by name
+    pub fn get(&self, name: &str) -> Option<&LensConverter> {
+        self.converters.get(name)
+    }
+
+    /// add or replace converter
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        converter: impl Fn(&dyn Reflect, &mut dyn Reflect) -> bool + 'static + Send + Sync,
+    ) {
+        self.converters.insert(name.into(), Box::new(converter));
+    }
+}
+
+macro_rules! impl_convert_reflect {
+    ($($t:ty),*) => {
+        /// format primitive or String value as string
+        pub fn reflect_to_string(value: &dyn Reflect) -> Option<String> {
+            $(
+                if let Some(v) = value.downcast_ref::<$t>() {
+                    return Some(v.to_string());
+                }
+            )*
+            None
+        }
+
+        /// parse string into primitive or String value, false if type is not
+        /// supported or parse failed
+        pub fn reflect_parse(target: &mut dyn Reflect, s: &str) -> bool {
+            $(
+                if let Some(v) = target.downcast_mut::<$t>() {
+                    return match s.parse::<$t>() {
+                        Ok(parsed) => {
+                            *v = parsed;
+                            true
+                        }
+                        Err(_) => false,
+                    };
+                }
+            )*
+            false
+        }
+    };
+}
+
+impl_convert_reflect!(
+    String, bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+/// copy value of same type, otherwise convert primitive and String values
+/// in Display / FromStr style, false if it can not be converted
+pub fn convert_reflect(source: &dyn Reflect, target: &mut dyn Reflect) -> bool {
+    if source.type_name() == target.type_name() {
+        target.apply(source);
+        return true;
+    }
+    match reflect_to_string(source) {
+        Some(s) => reflect_parse(target, &s),
+        None => false,
+    }
+}
+
+/// copy source field to target field, with converter if any
+pub fn apply_lens(
+    source: &dyn Reflect,
+    source_path: &str,
+    target: &mut dyn Reflect,
+    target_path: &str,
+    converter: Option<&LensConverter>,
+) -> Result<(), String> {
+    let source_field = source
+        .path(source_path)
+        .map_err(|e| format!("source path {}: {:?}", source_path, e))?;
+    let target_field = target
+        .path_mut(target_path)
+        .map_err(|e| format!("target path {}: {:?}", target_path, e))?;
+    match converter {
+        Some(converter) => {
+            if !converter(source_field, target_field) {
+                return Err(format!(
+                    "can not convert {} to {}",
+                    source_field.type_name(),
+                    target_field.type_name()
+                ));
+            }
+        }
+        None => {
+            if !convert_reflect(source_field, target_field) {
+                return Err(format!(
+                    "can not convert {} to {}, converter is needed",
+                    source_field.type_name(),
+                    target_field.type_name()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn lens_bind_system<S: Component + Reflect, T: Component + Reflect>(
+    propagation: Res<BindPropagation>,
+    index: Res<BindingIndex<LensBind<S, T>>>,
+    converters: Res<LensConverters>,
+    changed_source_query: Query<Entity, Changed<S>>,
+    source_query: Query<(&S, ChangeTrackers<S>)>,
+    mut target_query: Query<(&LensBind<S, T>, &mut T)>,
+    changed_query: NewBindings<LensBind<S, T>>,
+) {
+    let mut binding_entities: Vec<Entity> = changed_source_query
+        .iter()
+        .flat_map(|source_entity| index.get(source_entity).iter().copied())
+        .collect();
+    // initial sync, changed sources are already collected above
+    for (binding_entity, lens) in changed_query.iter() {
+        if let Ok((_, tracker)) = source_query.get(lens.source) {
+            if !tracker.is_changed() {
+                binding_entities.push(binding_entity);
+            }
+        }
+    }
+
+    for binding_entity in binding_entities {
+        let Ok((lens, mut t)) = target_query.get_mut(binding_entity) else {
+            continue;
+        };
+        let Ok((s, _)) = source_query.get(lens.source) else {
+            continue;
+        };
+        let converter = match &lens.converter {
+            Some(name) => match converters.get(name) {
+                Some(converter) => Some(converter),
+                None => {
+                    warn!("lens converter {} not found", name);
+                    continue;
+                }
+            },
+            None => None,
+        };
+        if let Err(err) = apply_lens(
+            s,
+            &lens.source_path,
+            t.as_mut(),
+            &lens.target_path,
+            converter,
+        ) {
+            warn!("lens bind {:?}: {}", binding_entity, err);
+        }
+        propagation.mark_fired();
+    }
+}
+
+impl<S: Component + Reflect, T: Component + Reflect> RegisterBinding for LensBind<S, T> {
+    fn register(stage: &mut BindPropagationStage, world: &mut World) {
+        if let Some(registry) = world.get_resource::<AppTypeRegistry>() {
+            registry.write().register::<Self>();
+        }
+        world.init_resource::<LensConverters>();
+        stage.add_binding_index::<Self>(world);
+        stage.add_handler_system(lens_bind_system::<S, T>);
+        stage.add_cleanup_system::<Self>();
+    }
+}
+
+/// type erased lens binding, source and target components are found by
+/// type name in AppTypeRegistry, e.g. source `Inventory` at `"items[0].1"`
+/// bound to target `Text` at `"sections[0].value"`, also copied once when
+/// added
+///
+/// components need `#[reflect(Component)]` and `app.register_type::<C>()`,
+/// full or short type name can be used. fields are converted by
+/// convert_reflect(), or named converter from LensConverters
+///
+/// registered by UiBuilderPlugin, usable in ui template
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct ReflectBind {
+    pub source: Entity,
+    pub source_component: String,
+    pub source_path: String,
+    pub target_component: String,
+    pub target_path: String,
+    /// name of converter in LensConverters
+    pub converter: Option<String>,
+}
+
+impl ReflectBind {
+    /// source entity is set by `with_reflect_bind()`
+    pub fn new(
+        source_component: impl Into<String>,
+        source_path: impl Into<String>,
+        target_component: impl Into<String>,
+        target_path: impl Into<String>,
+    ) -> Self {
+        Self {
+            source: Entity::from_raw(u32::MAX),
+            source_component: source_component.into(),
+            source_path: source_path.into(),
+            target_component: target_component.into(),
+            target_path: target_path.into(),
+            converter: None,
+        }
+    }
+
+    /// use named converter from LensConverters
+    pub fn with_converter(mut self, converter: impl Into<String>) -> Self {
+        self.converter = Some(converter.into());
+        self
+    }
+}
+
+impl FromWorld for ReflectBind {
+    fn from_world(_world: &mut World) -> Self {
+        Self::new("", "", "", "")
+    }
+}
+
+impl MapEntities for ReflectBind {
+    fn map_entities(&mut self, entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
+        if let Ok(mapped_entity) = entity_map.get(self.source) {
+            self.source = mapped_entity;
+        }
+        Ok(())
+    }
+}
+
+/// find ReflectComponent and full type name by full or short type name
+fn reflect_component(
+    registry: &TypeRegistryInternal,
+    name: &str,
+) -> Option<(ReflectComponent, &'static str)> {
+    let registration = registry
+        .get_with_name(name)
+        .or_else(|| registry.get_with_short_name(name))?;
+    let reflect_component = registration.data::<ReflectComponent>()?.clone();
+    Some((reflect_component, registration.type_name()))
+}
+
+/// bindings of a (source entity, source component) pair in ReflectBindIndex
+struct ReflectBindSource {
+    component: ReflectComponent,
+    /// binding entity and its resolved target component
+    bindings: Vec<(Entity, ReflectComponent)>,
+}
+
+/// ReflectBind bindings indexed by source entity and component, components
+/// are resolved from AppTypeRegistry once when binding is added or changed
+#[derive(Resource, Default)]
+pub struct ReflectBindIndex {
+    sources: HashMap<(Entity, &'static str), ReflectBindSource>,
+    bindings: HashMap<Entity, (Entity, &'static str)>,
+}
+
+impl ReflectBindIndex {
+    /// get source entity of binding entity
+    pub fn source(&self, binding_entity: Entity) -> Option<Entity> {
+        self.bindings
+            .get(&binding_entity)
+            .map(|(source, _)| *source)
+    }
+
+    fn insert(
+        &mut self,
+        binding_entity: Entity,
+        source: (Entity, &'static str),
+        source_component: ReflectComponent,
+        target_component: ReflectComponent,
+    ) {
+        self.remove(binding_entity);
+        self.bindings.insert(binding_entity, source);
+        self.sources
+            .entry(source)
+            .or_insert_with(|| ReflectBindSource {
+                component: source_component,
+                bindings: vec![],
+            })
+            .bindings
+            .push((binding_entity, target_component));
+    }
+
+    /// remove binding entity, return its source
+    pub fn remove(&mut self, binding_entity: Entity) -> Option<Entity> {
+        let key = self.bindings.remove(&binding_entity)?;
+        if let Some(source) = self.sources.get_mut(&key) {
+            source.bindings.retain(|(e, _)| *e != binding_entity);
+            if source.bindings.is_empty() {
+                self.sources.remove(&key);
+            }
+        }
+        Some(key.0)
+    }
+
+    /// resolve components of added or changed binding, not indexed if any of
+    /// them is not registered
+    fn index_binding(
+        &mut self,
+        registry: &TypeRegistryInternal,
+        binding_entity: Entity,
+        bind: &ReflectBind,
+    ) {
+        self.remove(binding_entity);
+        let Some((source_component, source_type)) =
+            reflect_component(registry, &bind.source_component)
+        else {
+            warn!(
+                "reflect bind: component {} not registered",
+                bind.source_component
+            );
+            return;
+        };
+        let Some((target_component, _)) = reflect_component(registry, &bind.target_component)
+        else {
+            warn!(
+                "reflect bind: component {} not registered",
+                bind.target_component
+            );
+            return;
+        };
+        self.insert(
+            binding_entity,
+            (bind.source, source_type),
+            source_component,
+            target_component,
+        );
+    }
+}
+
+/// checks each indexed source once per iteration, only bindings of changed
+/// sources and added or changed bindings are applied
+pub fn reflect_bind_system(world: &mut World) {
+    let mut query = world.query_filtered::<(Entity, &ReflectBind), Changed<ReflectBind>>();
+    world.resource_scope(|world, mut index: Mut<ReflectBindIndex>| {
+        let mut changed_binds = HashSet::new();
+        if !query.is_empty(world, world.last_change_tick(), world.read_change_tick()) {
+            let registry = world.resource::<AppTypeRegistry>().clone();
+            let registry = registry.read();
+            for (binding_entity, bind) in query.iter(world) {
+                index.index_binding(&registry, binding_entity, bind);
+                changed_binds.insert(binding_entity);
+            }
+        }
+
+        let mut broken = vec![];
+        let mut fired = vec![];
+        for (&(source_entity, source_type), source) in index.sources.iter() {
+            if world.get_entity(source_entity).is_none() {
+                broken.push((source_entity, source_type));
+                continue;
+            }
+            let Some(value) = source.component.reflect_mut(world, source_entity) else {
+                continue;
+            };
+            let source_changed = value.is_changed();
+            let mut bindings = source
+                .bindings
+                .iter()
+                .filter(|(e, _)| source_changed || changed_binds.contains(e))
+                .peekable();
+            if bindings.peek().is_none() {
+                continue;
+            }
+            let value: Arc<dyn Reflect> = value.clone_value().into();
+            fired.extend(bindings.map(|(e, target)| (*e, target.clone(), value.clone())));
+        }
+
+        for key in broken {
+            let Some(source) = index.sources.remove(&key) else {
+                continue;
+            };
+            for (binding_entity, _) in source.bindings {
+                index.bindings.remove(&binding_entity);
+                send_binding_broken_world(
+                    world,
+                    BindingBroken {
+                        binding_entity,
+                        remote_entity: key.0,
+                        binding_type: type_name::<ReflectBind>(),
+                        remote_type: key.1,
+                    },
+                );
+                if let Some(mut entity) = world.get_entity_mut(binding_entity) {
+                    entity.remove::<ReflectBind>();
+                }
+            }
+        }
+
+        if fired.is_empty() {
+            return;
+        }
+        world.resource_scope(|world, converters: Mut<LensConverters>| {
+            for (binding_entity, target_component, value) in fired {
+                // cloned only when applied, world is borrowed mutably below
+                let Some(bind) = world.get::<ReflectBind>(binding_entity).cloned() else {
+                    continue;
+                };
+                let converter = match &bind.converter {
+                    Some(name) => match converters.get(name) {
+                        Some(converter) => Some(converter),
+                        None => {
+                            warn!("lens converter {} not found", name);
+                            continue;
+                        }
+                    },
+                    None => None,
+                };
+                if let Some(mut target) = target_component.reflect_mut(world, binding_entity) {
+                    if let Err(err) = apply_lens(
+                        value.as_ref(),
+                        &bind.source_path,
+                        &mut *target,
+                        &bind.target_path,
+                        converter,
+                    ) {
+                        warn!("reflect bind {:?}: {}", binding_entity, err);
+                    }
+                    world.resource::<BindPropagation>().mark_fired();
+                }
+            }
+        });
+    });
+}
+
+/// drop removed ReflectBind from index, runs in UiBindCleanupStage
+pub fn reflect_bind_index_cleanup_system(
+    mut index: ResMut<ReflectBindIndex>,
+    binding_query: Query<(), With<ReflectBind>>,
+    removed: RemovedComponents<ReflectBind>,
+) {
+    for binding_entity in removed.iter() {
+        // removed and inserted again in this frame
+        if !binding_query.contains(binding_entity) {
+            index.remove(binding_entity);
+        }
+    }
+}
+
+impl RegisterBinding for ReflectBind {
+    fn register(stage: &mut BindPropagationStage, world: &mut World) {
+        world.init_resource::<LensConverters>();
+        world.init_resource::<ReflectBindIndex>();
+        stage.add_exclusive_handler_system(reflect_bind_system);
+        stage.add_cleanup(reflect_bind_index_cleanup_system);
+    }
+}
+
+pub trait UiBuilderLensExt {
+    fn with_lens_bind<S: Component + Reflect, T: Component + Reflect>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        source_path: impl Into<String>,
+        target_path: impl Into<String>,
+    ) -> &mut Self;
+
+    fn with_lens_bind_converter<S: Component + Reflect, T: Component + Reflect>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        source_path: impl Into<String>,
+        target_path: impl Into<String>,
+        converter: impl Into<String>,
+    ) -> &mut Self;
+
+    fn with_reflect_bind(&mut self, source: impl Into<UiEntityRef>, bind: ReflectBind)
+        -> &mut Self;
+}
+
+impl<'a, B: UiBuilderBackend, C> UiBuilderLensExt for UiBuilder<'a, B, C> {
+    /// when remote entity `source` component `S` change, copy field at
+    /// `source_path` to current entity component `T` field at `target_path`
+    fn with_lens_bind<S: Component + Reflect, T: Component + Reflect>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        source_path: impl Into<String>,
+        target_path: impl Into<String>,
+    ) -> &mut Self {
+        let source_path = source_path.into();
+        let target_path = target_path.into();
+        self.insert_last_with_ref(source, move |source| {
+            LensBind::<S, T>::new(source, source_path, target_path)
+        });
+        self.register_binding::<LensBind<S, T>>();
+        self
+    }
+
+    /// same as with_lens_bind(), field is converted by named converter
+    ///
+    /// app.register_lens_converter() is needed
+    fn with_lens_bind_converter<S: Component + Reflect, T: Component + Reflect>(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        source_path: impl Into<String>,
+        target_path: impl Into<String>,
+        converter: impl Into<String>,
+    ) -> &mut Self {
+        let source_path = source_path.into();
+        let target_path = target_path.into();
+        let converter = converter.into();
+        self.insert_last_with_ref(source, move |source| {
+            LensBind::<S, T>::new(source, source_path, target_path).with_converter(converter)
+        });
+        self.register_binding::<LensBind<S, T>>();
+        self
+    }
+
+    /// type erased lens binding, when remote entity `source` component
+    /// `bind.source_component` change, copy field to current entity
+    /// component `bind.target_component`
+    fn with_reflect_bind(
+        &mut self,
+        source: impl Into<UiEntityRef>,
+        bind: ReflectBind,
+    ) -> &mut Self {
+        self.insert_last_with_ref(source, move |source| ReflectBind { source, ..bind });
+        self.register_binding::<ReflectBind>();
+        self
+    }
+}
+
+pub trait AppLensExt {
+    fn register_lens_bind<S: Component + Reflect, T: Component + Reflect>(&mut self) -> &mut Self;
+    fn register_lens_converter(
+        &mut self,
+        name: impl Into<String>,
+        converter: impl Fn(&dyn Reflect, &mut dyn Reflect) -> bool + 'static + Send + Sync,
+    ) -> &mut Self;
+}
+
+impl AppLensExt for App {
+    /// register lens bind (S, T), also register LensBind<S, T> type
+    fn register_lens_bind<S: Component + Reflect, T: Component + Reflect>(&mut self) -> &mut Self {
+        self.register_binding::<LensBind<S, T>>()
+    }
+
+    /// register named converter, e.g. `"u32_to_string"`
+    fn register_lens_converter(
+        &mut self,
+        name: impl Into<String>,
+        converter: impl Fn(&dyn Reflect, &mut dyn Reflect) -> bool + 'static + Send + Sync,
+    ) -> &mut Self {
+        self.init_resource::<LensConverters>();
+        self.world
+            .resource_mut::<LensConverters>()
+            .insert(name, converter);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UiBuilderPlugin;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Score(u32);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Label(String);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Stats {
+        hp: u32,
+        name: String,
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(bevy::input::InputPlugin)
+            .add_plugin(UiBuilderPlugin)
+            .register_type::<Score>()
+            .register_type::<Label>()
+            .register_type::<Stats>();
+        app
+    }
+
+    fn spawn_lens_bind(app: &mut App, source: Entity, converter: Option<&'static str>) -> Entity {
+        let mut b = UiBuilder::new(&mut app.world, ());
+        b.node().with_component(Label::default());
+        match converter {
+            Some(converter) => {
+                b.with_lens_bind_converter::<Stats, Label>(source, "hp", "0", converter)
+            }
+            None => b.with_lens_bind::<Stats, Label>(source, "hp", "0"),
+        };
+        let target = b.last();
+        b.finish().unwrap();
+        target
+    }
+
+    fn spawn_reflect_bind(app: &mut App, source: Entity, bind: ReflectBind) -> Entity {
+        let mut b = UiBuilder::new(&mut app.world, ());
+        b.node()
+            .with_component(Label::default())
+            .with_reflect_bind(source, bind);
+        let target = b.last();
+        b.finish().unwrap();
+        target
+    }
+
+    fn label(app: &App, e: Entity) -> &str {
+        &app.world.get::<Label>(e).unwrap().0
+    }
+
+    #[test]
+    fn reflect_bind_applies_changed_source() {
+        let mut app = test_app();
+        let source = app.world.spawn(Score(1)).id();
+        let target = spawn_reflect_bind(
+            &mut app,
+            source,
+            ReflectBind::new("Score", "0", "Label", "0"),
+        );
+        app.update();
+        assert_eq!(label(&app, target), "1");
+        assert_eq!(
+            app.world.resource::<ReflectBindIndex>().source(target),
+            Some(source)
+        );
+
+        // unchanged source is not applied again
+        app.world.get_mut::<Label>(target).unwrap().0 = "edited".into();
+        app.update();
+        assert_eq!(label(&app, target), "edited");
+
+        app.world.get_mut::<Score>(source).unwrap().0 = 2;
+        app.update();
+        assert_eq!(label(&app, target), "2");
+    }
+
+    #[test]
+    fn reflect_bind_unregistered_component_is_not_indexed() {
+        let mut app = test_app();
+        let source = app.world.spawn(Score(1)).id();
+        let target = spawn_reflect_bind(
+            &mut app,
+            source,
+            ReflectBind::new("Missing", "0", "Label", "0"),
+        );
+        app.update();
+        assert_eq!(label(&app, target), "");
+        assert_eq!(
+            app.world.resource::<ReflectBindIndex>().source(target),
+            None
+        );
+    }
+
+    #[test]
+    fn reflect_bind_broken_source() {
+        let mut app = test_app();
+        let source = app.world.spawn(Score(1)).id();
+        let target = spawn_reflect_bind(
+            &mut app,
+            source,
+            ReflectBind::new("Score", "0", "Label", "0"),
+        );
+        app.update();
+        app.world.despawn(source);
+        app.update();
+
+        let events = app.world.resource::<Events<BindingBroken>>();
+        let broken: Vec<_> = events.get_reader().iter(events).cloned().collect();
+        assert_eq!(
+            broken,
+            vec![BindingBroken {
+                binding_entity: target,
+                remote_entity: source,
+                binding_type: type_name::<ReflectBind>(),
+                remote_type: type_name::<Score>(),
+            }]
+        );
+        assert!(app.world.get::<ReflectBind>(target).is_none());
+        assert_eq!(
+            app.world.resource::<ReflectBindIndex>().source(target),
+            None
+        );
+    }
+
+    #[test]
+    fn removed_reflect_bind_leaves_index() {
+        let mut app = test_app();
+        let source = app.world.spawn(Score(1)).id();
+        let target = spawn_reflect_bind(
+            &mut app,
+            source,
+            ReflectBind::new("Score", "0", "Label", "0"),
+        );
+        app.update();
+        app.world.entity_mut(target).remove::<ReflectBind>();
+        app.update();
+        assert_eq!(
+            app.world.resource::<ReflectBindIndex>().source(target),
+            None
+        );
+    }
+
+    #[test]
+    fn lens_bind_copies_field_at_path() {
+        let mut app = test_app();
+        let source = app
+            .world
+            .spawn(Stats {
+                hp: 3,
+                name: "hero".into(),
+            })
+            .id();
+        let target = spawn_lens_bind(&mut app, source, None);
+        app.update();
+        assert_eq!(label(&app, target), "3");
+
+        app.world.get_mut::<Stats>(source).unwrap().hp = 4;
+        app.update();
+        assert_eq!(label(&app, target), "4");
+    }
+
+    #[test]
+    fn lens_bind_named_converter() {
+        let mut app = test_app();
+        app.register_lens_converter("hp_text", |source, target| {
+            match (
+                source.downcast_ref::<u32>(),
+                target.downcast_mut::<String>(),
+            ) {
+                (Some(hp), Some(text)) => {
+                    *text = format!("hp: {}", hp);
+                    true
+                }
+                _ => false,
+            }
+        });
+        let source = app.world.spawn(Stats::default()).id();
+        let target = spawn_lens_bind(&mut app, source, Some("hp_text"));
+        app.update();
+        assert_eq!(label(&app, target), "hp: 0");
+    }
+
+    #[test]
+    fn lens_bind_missing_converter_leaves_target() {
+        let mut app = test_app();
+        let source = app.world.spawn(Stats::default()).id();
+        let target = spawn_lens_bind(&mut app, source, Some("missing"));
+        app.update();
+        assert_eq!(label(&app, target), "");
+    }
+
+    #[test]
+    fn apply_lens_errors() {
+        let source = Stats {
+            hp: 1,
+            name: "hero".into(),
+        };
+        let mut target = Score(0);
+        assert!(apply_lens(&source, "missing", &mut target, "0", None).is_err());
+        assert!(apply_lens(&source, "hp", &mut target, "missing", None).is_err());
+        assert!(apply_lens(&source, "name", &mut target, "0", None).is_err());
+        assert_eq!(target.0, 0);
+        apply_lens(&source, "hp", &mut target, "0", None).unwrap();
+        assert_eq!(target.0, 1);
+    }
+}
@@ -1,14 +1,26 @@
 #![doc = include_str!("../README.md")]
 
+pub mod backend;
 pub mod bind;
 pub mod builder;
 pub mod buttons;
+pub mod error;
 pub mod helpers;
+pub mod lens;
 pub mod modifiers;
+pub mod names;
+pub mod observable;
 pub mod prelude;
+pub mod template;
+pub mod widgets;
 
+use backend::*;
 use bevy::prelude::*;
+use bind::*;
 use buttons::*;
+use lens::*;
+use names::*;
+use template::*;
 pub struct UiBuilderPlugin;
 
 impl Plugin for UiBuilderPlugin {
@@ -22,6 +34,24 @@ impl Plugin for UiBuilderPlugin {
             .register_type::<ImageButton>()
             .register_type::<ColorButton>()
             .register_type::<ButtonInternalState>()
-            .add_system(button_system);
+            .register_type::<UiNamePath>()
+            .register_type::<ReflectBind>()
+            .init_resource::<UiBuilderPendingChildren>()
+            .init_resource::<UiNames>()
+            .add_asset::<UiTemplate>()
+            .init_asset_loader::<UiTemplateLoader>()
+            .init_resource::<UiTemplatePreservedState>()
+            .init_resource::<BindingDiagnostics>()
+            .add_event::<BindingBroken>()
+            .init_resource::<LensConverters>()
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                ui_builder_pending_children_system.at_start(),
+            )
+            .add_system_to_stage(CoreStage::PostUpdate, ui_names_cleanup_system)
+            .add_system(button_system)
+            .add_system(ui_template_spawn_system)
+            .add_system(ui_template_hot_reload_system)
+            .register_binding::<ReflectBind>();
     }
 }
@@ -0,0 +1,211 @@
+use std::borrow::Cow;
+
+use bevy::{prelude::*, utils::HashMap};
+
+/// full path of unique named entity, e.g. `"settings/audio/volume_slider"`
+///
+/// inserted by `with_unique_name()`, entity is removed from UiNames when this
+/// component is removed or entity despawned
+#[derive(Component, Debug, Default, Clone, Reflect, PartialEq, Eq)]
+#[reflect(Component, Default, PartialEq)]
+pub struct UiNamePath(pub String);
+
+/// persistent registry of unique named entities, lives after UiBuilder is
+/// dropped, use it to find widgets by path in ordinary systems
+///
+/// path is scoped by `with_children()` nesting of unique named entities
+#[derive(Resource, Debug, Default, Clone)]
+pub struct UiNames {
+    entities: HashMap<String, Entity>,
+    paths: HashMap<Entity, String>,
+}
+
+impl UiNames {
+    /// get entity by full path
+    pub fn get(&self, path: &str) -> Option<Entity> {
+        self.entities.get(path).copied()
+    }
+
+    /// get entity by path relative to scope, then full path
+    pub fn find(&self, scope: &str, path: &str) -> Option<Entity> {
+        self.get(&ui_name_path(scope, path))
+            .or_else(|| self.get(path))
+    }
+
+    /// get full path of entity
+    pub fn path(&self, entity: Entity) -> Option<&str> {
+        self.paths.get(&entity).map(|p| p.as_str())
+    }
+
+    /// check if path is registered
+    pub fn contains(&self, path: &str) -> bool {
+        self.entities.contains_key(path)
+    }
+
+    /// register entity with full path, overwrite old entity with same path
+    pub fn insert(&mut self, path: impl Into<String>, entity: Entity) {
+        let path = path.into();
+        self.remove(entity);
+        if let Some(old) = self.entities.insert(path.clone(), entity) {
+            warn!("ui name {} is overwritten, old entity: {:?}", path, old);
+            self.paths.remove(&old);
+        }
+        self.paths.insert(entity, path);
+    }
+
+    /// unregister entity, return its path
+    pub fn remove(&mut self, entity: Entity) -> Option<String> {
+        let path = self.paths.remove(&entity)?;
+        self.entities.remove(&path);
+        Some(path)
+    }
+
+    /// iterate (path, entity) pairs
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Entity)> {
+        self.entities.iter().map(|(p, e)| (p.as_str(), *e))
+    }
+
+    /// iterate (path, entity) pairs under path, e.g. `"settings/audio"`
+    pub fn iter_under<'a>(&'a self, path: &'a str) -> impl Iterator<Item = (&'a str, Entity)> {
+        self.iter().filter(move |(p, _)| {
+            p.len() > path.len() && p.starts_with(path) && p[path.len()..].starts_with('/')
+        })
+    }
+}
+
+/// join name scope and name as full path
+pub fn ui_name_path(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", scope, name)
+    }
+}
+
+pub fn ui_names_cleanup_system(mut names: ResMut<UiNames>, removed: RemovedComponents<UiNamePath>) {
+    for entity in removed.iter() {
+        names.remove(entity);
+    }
+}
+
+/// entity reference of bindings, an entity, or unique name of an entity which
+/// can be declared later in the same UiBuilder
+///
+/// unique name is resolved right away if it is already declared, otherwise
+/// when `UiBuilder::finish()` is called
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UiEntityRef {
+    Entity(Entity),
+    Name(Cow<'static, str>),
+}
+
+impl From<Entity> for UiEntityRef {
+    fn from(e: Entity) -> Self {
+        UiEntityRef::Entity(e)
+    }
+}
+
+impl From<&'static str> for UiEntityRef {
+    fn from(name: &'static str) -> Self {
+        UiEntityRef::Name(name.into())
+    }
+}
+
+impl From<String> for UiEntityRef {
+    fn from(name: String) -> Self {
+        UiEntityRef::Name(name.into())
+    }
+}
+
+impl From<Cow<'static, str>> for UiEntityRef {
+    fn from(name: Cow<'static, str>) -> Self {
+        UiEntityRef::Name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{builder::UiBuilder, UiBuilderPlugin};
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(bevy::input::InputPlugin)
+            .add_plugin(UiBuilderPlugin);
+        app
+    }
+
+    fn spawn_settings(world: &mut World) -> Entity {
+        let mut b = UiBuilder::new(world, ());
+        b.node().with_unique_name("settings").with_children(|b| {
+            b.node().with_unique_name("audio").with_children(|b| {
+                b.node().with_unique_name("volume");
+            });
+            b.node().with_unique_name("video");
+        });
+        let settings = b.last();
+        b.finish().unwrap();
+        settings
+    }
+
+    #[test]
+    fn names_are_scoped_by_path() {
+        let mut app = test_app();
+        spawn_settings(&mut app.world);
+        let names = app.world.resource::<UiNames>();
+        let volume = names.get("settings/audio/volume").unwrap();
+        assert_eq!(names.path(volume), Some("settings/audio/volume"));
+        assert_eq!(
+            app.world.get::<UiNamePath>(volume).unwrap().0,
+            "settings/audio/volume"
+        );
+        assert!(names.get("volume").is_none());
+        assert_eq!(names.find("settings/audio", "volume"), Some(volume));
+        assert_eq!(names.find("other", "settings/audio/volume"), Some(volume));
+        assert_eq!(names.find("settings", "volume"), None);
+
+        let mut under: Vec<_> = names.iter_under("settings").map(|(p, _)| p).collect();
+        under.sort_unstable();
+        assert_eq!(
+            under,
+            ["settings/audio", "settings/audio/volume", "settings/video"]
+        );
+    }
+
+    #[test]
+    fn despawned_names_are_removed() {
+        let mut app = test_app();
+        let settings = spawn_settings(&mut app.world);
+        let audio = app
+            .world
+            .resource::<UiNames>()
+            .get("settings/audio")
+            .unwrap();
+        despawn_with_children_recursive(&mut app.world, audio);
+        app.update();
+
+        let names = app.world.resource::<UiNames>();
+        assert!(!names.contains("settings/audio"));
+        assert!(!names.contains("settings/audio/volume"));
+        assert!(names.contains("settings/video"));
+        assert_eq!(names.get("settings"), Some(settings));
+    }
+
+    #[test]
+    fn insert_overwrites_old_entity() {
+        let mut names = UiNames::default();
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        names.insert("a", a);
+        names.insert("a", b);
+        assert_eq!(names.get("a"), Some(b));
+        assert_eq!(names.path(a), None);
+
+        names.insert("b", b);
+        assert!(!names.contains("a"));
+        assert_eq!(names.remove(b), Some("b".to_string()));
+        assert_eq!(names.iter().count(), 0);
+    }
+}
@@ -0,0 +1,290 @@
+use std::{
+    any::TypeId,
+    ops::Deref,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use bevy::prelude::*;
+
+use crate::bind::BindPropagationStage;
+
+/// operation recorded by ObservableVec, indices are of the vec at the time
+/// the operation happened
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListOp {
+    /// item inserted at index
+    Insert(usize),
+    /// item at index removed
+    Remove(usize),
+    /// item removed at `from`, then inserted at `to`
+    Move { from: usize, to: usize },
+    /// item at index modified in place
+    Update(usize),
+    /// all items replaced, or operations since last read are lost
+    Reset,
+}
+
+const RESET: &[ListOp] = &[ListOp::Reset];
+
+/// op log longer than this (plus vec length) is collapsed into ListOp::Reset
+const MAX_PENDING_OPS: usize = 64;
+
+static NEXT_VEC_ID: AtomicU64 = AtomicU64::new(0);
+
+/// vec component recording insert, remove, move and update operations, so
+/// list bindings can apply them to child entities instead of rebuilding all
+///
+/// operations are kept until next frame bind stage, read them with a
+/// ListOpsCursor, see `with_bind_list_ops()` and
+/// `with_observable_list_binding()`
+#[derive(Component, Debug)]
+pub struct ObservableVec<T> {
+    id: u64,
+    items: Vec<T>,
+    ops: Vec<ListOp>,
+    /// sequence number of ops[0]
+    first_seq: u64,
+    /// ops before this index are removed by next trim
+    trim_len: usize,
+}
+
+impl<T> ObservableVec<T> {
+    pub fn new() -> Self {
+        Self::from(Vec::new())
+    }
+
+    /// operations still kept in log
+    pub fn ops(&self) -> &[ListOp] {
+        &self.ops
+    }
+
+    /// operations since cursor was last updated, cursor is moved to the end
+    ///
+    /// returns `[ListOp::Reset]` if cursor is new, from another vec, or
+    /// operations were already trimmed
+    pub fn read_ops(&self, cursor: &mut ListOpsCursor) -> &[ListOp] {
+        let end = self.first_seq + self.ops.len() as u64;
+        let ops = match cursor.0 {
+            Some((id, seq)) if id == self.id && seq >= self.first_seq && seq <= end => {
+                &self.ops[(seq - self.first_seq) as usize..]
+            }
+            _ => RESET,
+        };
+        cursor.0 = Some((self.id, end));
+        ops
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.record(ListOp::Insert(self.items.len()));
+        self.items.push(value);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let value = self.items.pop()?;
+        self.record(ListOp::Remove(self.items.len()));
+        Some(value)
+    }
+
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.items.insert(index, value);
+        self.record(ListOp::Insert(index));
+    }
+
+    pub fn remove(&mut self, index: usize) -> T {
+        let value = self.items.remove(index);
+        self.record(ListOp::Remove(index));
+        value
+    }
+
+    /// remove item at `from`, then insert it at `to`
+    pub fn move_item(&mut self, from: usize, to: usize) {
+        let value = self.items.remove(from);
+        self.items.insert(to, value);
+        if from != to {
+            self.record(ListOp::Move { from, to });
+        }
+    }
+
+    pub fn set(&mut self, index: usize, value: T) {
+        self.items[index] = value;
+        self.record(ListOp::Update(index));
+    }
+
+    /// get item for modify, recorded as ListOp::Update
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.items.len() {
+            return None;
+        }
+        self.record(ListOp::Update(index));
+        self.items.get_mut(index)
+    }
+
+    /// keep items matching predicate, removed items are recorded one by one
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let mut index = 0;
+        while index < self.items.len() {
+            if f(&self.items[index]) {
+                index += 1;
+            } else {
+                self.remove(index);
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.replace(Vec::new());
+    }
+
+    /// replace all items, recorded as ListOp::Reset
+    pub fn replace(&mut self, items: Vec<T>) {
+        self.items = items;
+        self.record(ListOp::Reset);
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+
+    fn record(&mut self, op: ListOp) {
+        if op == ListOp::Reset || self.ops.len() > self.items.len() + MAX_PENDING_OPS {
+            // readers of dropped ops get Reset
+            self.first_seq += self.ops.len() as u64;
+            self.ops.clear();
+            self.trim_len = 0;
+            self.ops.push(ListOp::Reset);
+            return;
+        }
+        self.ops.push(op);
+    }
+
+    /// remove ops kept since last trim, called once per frame
+    fn trim_ops(&mut self) {
+        self.ops.drain(..self.trim_len);
+        self.first_seq += self.trim_len as u64;
+        self.trim_len = self.ops.len();
+    }
+}
+
+impl<T> Default for ObservableVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Vec<T>> for ObservableVec<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self {
+            id: NEXT_VEC_ID.fetch_add(1, Ordering::Relaxed),
+            items,
+            ops: Vec::new(),
+            first_seq: 0,
+            trim_len: 0,
+        }
+    }
+}
+
+/// clone has its own op log, readers of the clone start with ListOp::Reset
+impl<T: Clone> Clone for ObservableVec<T> {
+    fn clone(&self) -> Self {
+        Self::from(self.items.clone())
+    }
+}
+
+impl<T> Deref for ObservableVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+/// read position in op log of an ObservableVec
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ListOpsCursor(Option<(u64, u64)>);
+
+/// trim op log read by every cursor, runs in UiBindCleanupStage once per frame
+pub fn observable_vec_trim_system<T: Send + Sync + 'static>(
+    mut query: Query<&mut ObservableVec<T>>,
+) {
+    for mut vec in query.iter_mut() {
+        vec.bypass_change_detection().trim_ops();
+    }
+}
+
+/// add observable_vec_trim_system::<T> once, called for list op bindings by
+/// UiBuilder::register_observable_vec(). without it op log is collapsed into
+/// ListOp::Reset when it grows too long
+pub fn register_observable_vec_trim<T: Send + Sync + 'static>(
+    stage: &mut BindPropagationStage,
+    _world: &mut World,
+) {
+    if stage.registered.insert(TypeId::of::<ObservableVec<T>>()) {
+        stage.add_cleanup(observable_vec_trim_system::<T>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn op_sequence_and_trim() {
+        let mut vec = ObservableVec::from(vec![1, 2]);
+        let mut cursor = ListOpsCursor::default();
+        assert_eq!(vec.read_ops(&mut cursor), RESET);
+        assert_eq!(vec.read_ops(&mut cursor), &[]);
+
+        vec.push(3);
+        vec.insert(0, 0);
+        vec.move_item(3, 1);
+        vec.move_item(2, 2);
+        vec.set(0, 10);
+        vec.remove(2);
+        assert_eq!(&*vec, &[10, 3, 2]);
+        let mut stale = cursor;
+        assert_eq!(
+            vec.read_ops(&mut cursor),
+            &[
+                ListOp::Insert(2),
+                ListOp::Insert(0),
+                ListOp::Move { from: 3, to: 1 },
+                ListOp::Update(0),
+                ListOp::Remove(2),
+            ]
+        );
+
+        // first trim keeps ops for readers running later in frame
+        vec.trim_ops();
+        vec.pop();
+        assert_eq!(vec.read_ops(&mut cursor), &[ListOp::Remove(2)]);
+        vec.trim_ops();
+        assert_eq!(vec.ops(), &[ListOp::Remove(2)]);
+        vec.trim_ops();
+        assert!(vec.ops().is_empty());
+        assert_eq!(vec.read_ops(&mut cursor), &[]);
+        assert_eq!(vec.read_ops(&mut stale), RESET);
+        assert_eq!(vec.read_ops(&mut stale), &[]);
+
+        vec.clear();
+        assert!(vec.is_empty());
+        assert_eq!(vec.read_ops(&mut cursor), RESET);
+    }
+
+    #[test]
+    fn long_op_log_collapses_into_reset() {
+        let mut vec = ObservableVec::from(vec![0]);
+        let mut cursor = ListOpsCursor::default();
+        vec.read_ops(&mut cursor);
+        // log longer than items plus MAX_PENDING_OPS collapses on next record
+        for i in 0..=MAX_PENDING_OPS + 2 {
+            vec.set(0, i);
+        }
+        assert_eq!(vec.read_ops(&mut cursor), RESET);
+        vec.set(0, 1);
+        assert_eq!(vec.read_ops(&mut cursor), &[ListOp::Update(0)]);
+
+        let mut other_cursor = ListOpsCursor::default();
+        vec.clone().read_ops(&mut other_cursor);
+        assert_eq!(vec.read_ops(&mut other_cursor), RESET);
+    }
+}
@@ -1,6 +1,13 @@
+pub use crate::backend::*;
 pub use crate::bind::*;
 pub use crate::builder::*;
 pub use crate::buttons::*;
+pub use crate::error::*;
 pub use crate::helpers::*;
+pub use crate::lens::*;
 pub use crate::modifiers::*;
+pub use crate::names::*;
+pub use crate::observable::*;
+pub use crate::template::*;
+pub use crate::widgets::*;
 pub use crate::UiBuilderPlugin;
@@ -0,0 +1,726 @@
+use std::any::TypeId;
+
+use bevy::{
+    asset::{AssetLoader, BoxedFuture, Error, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::{GetTypeRegistration, TypeUuid},
+    utils::HashMap,
+};
+use serde::Deserialize;
+
+use crate::{
+    backend::UiBuilderBackend, builder::UiBuilder, buttons::*, lens::*, modifiers::*, names::*,
+};
+
+/// ui template asset, describe ui hierarchy in data file (`*.ui.ron`)
+///
+/// every node maps onto UiBuilder calls, spawn with
+/// `UiBuilderTemplateExt::template()` or `UiTemplateRoot` component
+#[derive(Debug, Clone, Default, Deserialize, TypeUuid)]
+#[uuid = "a8d1c7c2-4f3e-4d1b-9d0e-3c6b5f1e2a70"]
+#[serde(default)]
+pub struct UiTemplate {
+    /// default text style used by text nodes inside this template
+    pub text_style: Option<UiTemplateTextStyle>,
+    pub root: UiTemplateNode,
+}
+
+/// default text style of template, font is an asset path
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct UiTemplateTextStyle {
+    pub font: Option<String>,
+    pub font_size: f32,
+    pub color: Color,
+}
+
+impl Default for UiTemplateTextStyle {
+    fn default() -> Self {
+        Self {
+            font: None,
+            font_size: 12.,
+            color: Color::WHITE,
+        }
+    }
+}
+
+/// kind of template node, maps onto node(), text(), image(), button()
+#[derive(Debug, Clone, Default, Deserialize)]
+pub enum UiTemplateNodeKind {
+    #[default]
+    Node,
+    /// text content
+    Text(String),
+    /// image asset path
+    Image(String),
+    Button,
+}
+
+/// one node of template, each optional field maps onto a `with_*` call
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct UiTemplateNode {
+    pub kind: UiTemplateNodeKind,
+    /// with_name()
+    pub name: Option<String>,
+    /// with_unique_name()
+    pub unique_name: Option<String>,
+    /// with_style_modifier()
+    pub style: Vec<UiTemplateStyle>,
+    /// with_text_modifier(), only for text node
+    pub text: Vec<UiTemplateText>,
+    /// with_bg_color()
+    pub bg_color: Option<Color>,
+    /// with_visibility()
+    pub visible: Option<bool>,
+    /// with_disabled()
+    pub disabled: Option<bool>,
+    /// with_toggle()
+    pub toggle: Option<bool>,
+    /// with_toggle_group()
+    pub toggle_group: Option<String>,
+    /// with_action_on_release()
+    pub action_on_release: bool,
+    /// with_color_button()
+    pub color_button: Option<UiTemplateColorButton>,
+    /// with_image_button()
+    pub image_button: Option<UiTemplateImageButton>,
+    /// with_reflect_bind()
+    pub binds: Vec<UiTemplateBind>,
+    /// with_children()
+    pub children: Vec<UiTemplateNode>,
+}
+
+/// type erased binding of template node, maps onto ReflectBind
+///
+/// e.g. `(source: "inventory", source_component: "Inventory", source_path:
+/// "items[0].1", target_component: "Text", target_path: "sections[0].value")`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct UiTemplateBind {
+    /// unique name of source entity, can be declared later in template
+    pub source: String,
+    pub source_component: String,
+    pub source_path: String,
+    pub target_component: String,
+    pub target_path: String,
+    /// name of converter in LensConverters
+    pub converter: Option<String>,
+}
+
+impl From<&UiTemplateBind> for ReflectBind {
+    fn from(bind: &UiTemplateBind) -> Self {
+        ReflectBind {
+            converter: bind.converter.clone(),
+            ..ReflectBind::new(
+                bind.source_component.clone(),
+                bind.source_path.clone(),
+                bind.target_component.clone(),
+                bind.target_path.clone(),
+            )
+        }
+    }
+}
+
+/// rect of template style, unset side is Val::Undefined
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct UiTemplateRect {
+    pub left: Val,
+    pub right: Val,
+    pub top: Val,
+    pub bottom: Val,
+}
+
+impl From<UiTemplateRect> for UiRect {
+    fn from(rect: UiTemplateRect) -> Self {
+        UiRect {
+            left: rect.left,
+            right: rect.right,
+            top: rect.top,
+            bottom: rect.bottom,
+        }
+    }
+}
+
+/// template style entry, each variant maps onto a StyleModifier
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum UiTemplateStyle {
+    Display(Display),
+    PositionType(PositionType),
+    Direction(Direction),
+    FlexDirection(FlexDirection),
+    FlexWrap(FlexWrap),
+    AlignItems(AlignItems),
+    AlignSelf(AlignSelf),
+    AlignContent(AlignContent),
+    JustifyContent(JustifyContent),
+    Position(UiTemplateRect),
+    Margin(UiTemplateRect),
+    Padding(UiTemplateRect),
+    Border(UiTemplateRect),
+    FlexGrow(f32),
+    FlexShrink(f32),
+    FlexBasis(Val),
+    /// width, height
+    Size(Val, Val),
+    /// width, height
+    MinSize(Val, Val),
+    /// width, height
+    MaxSize(Val, Val),
+    AspectRatio(Option<f32>),
+    Overflow(Overflow),
+    CenterChildren,
+}
+
+impl StyleModifier for UiTemplateStyle {
+    fn modify(self, style: &mut Style) {
+        match self {
+            UiTemplateStyle::Display(v) => v.modify(style),
+            UiTemplateStyle::PositionType(v) => v.modify(style),
+            UiTemplateStyle::Direction(v) => v.modify(style),
+            UiTemplateStyle::FlexDirection(v) => v.modify(style),
+            UiTemplateStyle::FlexWrap(v) => v.modify(style),
+            UiTemplateStyle::AlignItems(v) => v.modify(style),
+            UiTemplateStyle::AlignSelf(v) => v.modify(style),
+            UiTemplateStyle::AlignContent(v) => v.modify(style),
+            UiTemplateStyle::JustifyContent(v) => v.modify(style),
+            UiTemplateStyle::Position(v) => StylePosition(v.into()).modify(style),
+            UiTemplateStyle::Margin(v) => StyleMargin(v.into()).modify(style),
+            UiTemplateStyle::Padding(v) => StylePadding(v.into()).modify(style),
+            UiTemplateStyle::Border(v) => StyleBorder(v.into()).modify(style),
+            UiTemplateStyle::FlexGrow(v) => StyleFlexGrow(v).modify(style),
+            UiTemplateStyle::FlexShrink(v) => StyleFlexShrink(v).modify(style),
+            UiTemplateStyle::FlexBasis(v) => StyleFlexBasis(v).modify(style),
+            UiTemplateStyle::Size(w, h) => StyleSize::new(w, h).modify(style),
+            UiTemplateStyle::MinSize(w, h) => StyleMinSize::new(w, h).modify(style),
+            UiTemplateStyle::MaxSize(w, h) => StyleMaxSize::new(w, h).modify(style),
+            UiTemplateStyle::AspectRatio(v) => StyleAspectRatio(v).modify(style),
+            UiTemplateStyle::Overflow(v) => v.modify(style),
+            UiTemplateStyle::CenterChildren => StyleCenterChildren.modify(style),
+        }
+    }
+}
+
+impl StyleModifier for Vec<UiTemplateStyle> {
+    fn modify(self, style: &mut Style) {
+        for item in self {
+            item.modify(style);
+        }
+    }
+}
+
+/// template text entry, each variant maps onto a TextModifier
+#[derive(Debug, Clone, Deserialize)]
+pub enum UiTemplateText {
+    /// font asset path
+    Font(String),
+    FontSize(f32),
+    Color(Color),
+    HorizontalAlign(HorizontalAlign),
+    VerticalAlign(VerticalAlign),
+}
+
+/// template text entries with asset server to resolve font path
+struct UiTemplateTextModifier<'a> {
+    items: &'a [UiTemplateText],
+    asset_server: &'a AssetServer,
+}
+
+impl<'a> TextModifier for UiTemplateTextModifier<'a> {
+    fn modify(self, text: &mut Text) {
+        for item in self.items {
+            match item {
+                UiTemplateText::Font(path) => self
+                    .asset_server
+                    .load::<Font, _>(path.as_str())
+                    .modify(text),
+                UiTemplateText::FontSize(v) => v.modify(text),
+                UiTemplateText::Color(v) => v.modify(text),
+                UiTemplateText::HorizontalAlign(v) => v.modify(text),
+                UiTemplateText::VerticalAlign(v) => v.modify(text),
+            }
+        }
+    }
+}
+
+/// template version of ColorButton, unset color is same as normal
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct UiTemplateColorButton {
+    pub normal: Color,
+    pub disabled: Option<Color>,
+    pub pressed: Option<Color>,
+    pub hovered: Option<Color>,
+    pub pressed_hovered: Option<Color>,
+}
+
+impl From<&UiTemplateColorButton> for ColorButton {
+    fn from(value: &UiTemplateColorButton) -> Self {
+        Self {
+            normal: value.normal,
+            disabled: value.disabled.unwrap_or(value.normal),
+            pressed: value.pressed.unwrap_or(value.normal),
+            hovered: value.hovered.unwrap_or(value.normal),
+            pressed_hovered: value.pressed_hovered.unwrap_or(value.normal),
+        }
+    }
+}
+
+/// template version of ImageButton, values are image asset paths,
+/// unset image is same as normal
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct UiTemplateImageButton {
+    pub normal: String,
+    pub disabled: Option<String>,
+    pub pressed: Option<String>,
+    pub normal_hovered: Option<String>,
+    pub pressed_hovered: Option<String>,
+}
+
+impl UiTemplateImageButton {
+    fn load(&self, asset_server: &AssetServer) -> ImageButton {
+        let load = |path: &Option<String>| -> Handle<Image> {
+            asset_server.load(path.as_ref().unwrap_or(&self.normal).as_str())
+        };
+        ImageButton {
+            normal: asset_server.load(self.normal.as_str()),
+            disabled: load(&self.disabled),
+            pressed: load(&self.pressed),
+            normal_hovered: load(&self.normal_hovered),
+            pressed_hovered: load(&self.pressed_hovered),
+        }
+    }
+}
+
+/// load `*.ui.ron` file as UiTemplate
+#[derive(Default)]
+pub struct UiTemplateLoader;
+
+impl AssetLoader for UiTemplateLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), Error>> {
+        Box::pin(async move {
+            let template = ron::de::from_bytes::<UiTemplate>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(template));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ui.ron"]
+    }
+}
+
+/// spawn template `0` as children of current entity once the asset is loaded
+#[derive(Component, Debug, Default, Clone)]
+pub struct UiTemplateRoot(pub Handle<UiTemplate>);
+
+/// marker, template of UiTemplateRoot is already spawned
+#[derive(Component, Debug, Default, Clone)]
+pub struct UiTemplateSpawned;
+
+/// build template as children of parent, template errors are logged instead
+/// of panic
+fn spawn_template<B: UiBuilderBackend>(
+    backend: &mut B,
+    parent: Entity,
+    template: &UiTemplate,
+    asset_server: &AssetServer,
+) {
+    let mut b = UiBuilder::new(backend, ());
+    b.set_collect_errors(true)
+        .set_parent(parent)
+        .template(template, asset_server);
+    if let Err(errors) = b.finish() {
+        for err in errors {
+            error!("ui template: {}", err);
+        }
+    }
+}
+
+pub fn ui_template_spawn_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    templates: Res<Assets<UiTemplate>>,
+    query: Query<(Entity, &UiTemplateRoot), Without<UiTemplateSpawned>>,
+) {
+    for (entity, root) in query.iter() {
+        if let Some(template) = templates.get(&root.0) {
+            spawn_template(&mut commands, entity, template, &asset_server);
+            commands.entity(entity).insert(UiTemplateSpawned);
+        }
+    }
+}
+
+/// component types copied from old template nodes to new template nodes with
+/// the same unique name path when template asset is hot reloaded
+///
+/// component must be registered with `#[reflect(Component)]`
+#[derive(Resource, Debug, Clone)]
+pub struct UiTemplatePreservedState {
+    pub components: Vec<TypeId>,
+}
+
+impl Default for UiTemplatePreservedState {
+    fn default() -> Self {
+        Self {
+            components: vec![TypeId::of::<ToggleButton>(), TypeId::of::<Disabled>()],
+        }
+    }
+}
+
+pub trait AppTemplateExt {
+    fn preserve_template_component<T: Component + Reflect + GetTypeRegistration>(
+        &mut self,
+    ) -> &mut Self;
+}
+
+impl AppTemplateExt for App {
+    /// keep component T of named template nodes when template asset is hot reloaded
+    ///
+    /// T need `#[reflect(Component)]`
+    fn preserve_template_component<T: Component + Reflect + GetTypeRegistration>(
+        &mut self,
+    ) -> &mut Self {
+        self.register_type::<T>();
+        self.world
+            .get_resource_or_insert_with(UiTemplatePreservedState::default)
+            .components
+            .push(TypeId::of::<T>());
+        self
+    }
+}
+
+/// rebuild spawned template subtrees when template asset modified
+///
+/// need `AssetPlugin { watch_for_changes: true, .. }`
+pub fn ui_template_hot_reload_system(
+    mut commands: Commands,
+    mut asset_events: EventReader<AssetEvent<UiTemplate>>,
+    query: Query<(Entity, &UiTemplateRoot), With<UiTemplateSpawned>>,
+) {
+    for ev in asset_events.iter() {
+        if let AssetEvent::Modified { handle } = ev {
+            for (entity, root) in query.iter() {
+                if root.0 == *handle {
+                    commands.add(move |world: &mut World| rebuild_template(world, entity));
+                }
+            }
+        }
+    }
+}
+
+/// descendants of entity with unique name, keyed by UiNamePath, nodes with
+/// duplicated path are skipped
+fn unique_named_descendants(world: &World, entity: Entity) -> HashMap<String, Entity> {
+    let mut named = HashMap::<String, Option<Entity>>::default();
+    let mut stack = vec![entity];
+    while let Some(e) = stack.pop() {
+        for child in world.get::<Children>(e).into_iter().flat_map(|c| c.iter()) {
+            if let Some(path) = world.get::<UiNamePath>(*child) {
+                named
+                    .entry(path.0.clone())
+                    .and_modify(|e| *e = None)
+                    .or_insert(Some(*child));
+            }
+            stack.push(*child);
+        }
+    }
+    named
+        .into_iter()
+        .filter_map(|(name, e)| e.map(|e| (name, e)))
+        .collect()
+}
+
+fn rebuild_template(world: &mut World, root_entity: Entity) {
+    let Some(handle) = world
+        .get::<UiTemplateRoot>(root_entity)
+        .map(|r| r.0.clone())
+    else {
+        return;
+    };
+    let Some(template) = world.resource::<Assets<UiTemplate>>().get(&handle).cloned() else {
+        return;
+    };
+
+    // save state of old nodes
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let preserved = world
+        .get_resource::<UiTemplatePreservedState>()
+        .cloned()
+        .unwrap_or_default();
+    let reflect_components = preserved
+        .components
+        .iter()
+        .filter_map(|type_id| type_registry.get_type_data::<ReflectComponent>(*type_id))
+        .collect::<Vec<_>>();
+    let mut saved = Vec::new();
+    for (path, entity) in unique_named_descendants(world, root_entity) {
+        for reflect_component in reflect_components.iter() {
+            if let Some(value) = reflect_component.reflect(world, entity) {
+                saved.push((path.clone(), *reflect_component, value.clone_value()));
+            }
+        }
+    }
+
+    // rebuild, old paths are released for new nodes
+    let mut old_nodes = Vec::new();
+    let mut stack = vec![root_entity];
+    while let Some(e) = stack.pop() {
+        for child in world.get::<Children>(e).into_iter().flat_map(|c| c.iter()) {
+            old_nodes.push(*child);
+            stack.push(*child);
+        }
+    }
+    if let Some(mut names) = world.get_resource_mut::<UiNames>() {
+        for e in old_nodes {
+            names.remove(e);
+        }
+    }
+    world.entity_mut(root_entity).despawn_descendants();
+    let asset_server = world.resource::<AssetServer>().clone();
+    spawn_template(world, root_entity, &template, &asset_server);
+
+    // restore state to new nodes
+    let new_nodes = unique_named_descendants(world, root_entity);
+    for (path, reflect_component, value) in saved {
+        if let Some(entity) = new_nodes.get(&path) {
+            reflect_component.apply_or_insert(world, *entity, &*value);
+        }
+    }
+}
+
+fn build_template_node<B: UiBuilderBackend, C>(
+    b: &mut UiBuilder<B, C>,
+    node: &UiTemplateNode,
+    asset_server: &AssetServer,
+) {
+    match &node.kind {
+        UiTemplateNodeKind::Node => b.node(),
+        UiTemplateNodeKind::Text(content) => b.text(content.clone()),
+        UiTemplateNodeKind::Image(path) => b.image(asset_server.load(path.as_str())),
+        UiTemplateNodeKind::Button => b.button(),
+    };
+
+    if let Some(name) = &node.name {
+        b.with_name(name.clone());
+    }
+    if let Some(name) = &node.unique_name {
+        b.with_unique_name(name.clone());
+    }
+    if !node.style.is_empty() {
+        b.with_style_modifier(node.style.clone());
+    }
+    if !node.text.is_empty() {
+        b.with_text_modifier(UiTemplateTextModifier {
+            items: &node.text,
+            asset_server,
+        });
+    }
+    if let Some(color) = node.bg_color {
+        b.with_bg_color(color);
+    }
+    if let Some(visible) = node.visible {
+        b.with_visibility(visible);
+    }
+    if let Some(disabled) = node.disabled {
+        b.with_disabled(disabled);
+    }
+    if let Some(toggle) = node.toggle {
+        b.with_toggle(toggle);
+    }
+    if let Some(group) = &node.toggle_group {
+        b.with_toggle_group(group.clone());
+    }
+    if node.action_on_release {
+        b.with_action_on_release();
+    }
+    if let Some(color_button) = &node.color_button {
+        b.with_color_button(color_button.into());
+    }
+    if let Some(image_button) = &node.image_button {
+        b.with_image_button(image_button.load(asset_server));
+    }
+    for bind in node.binds.iter() {
+        b.with_reflect_bind(bind.source.clone(), bind.into());
+    }
+    if !node.children.is_empty() {
+        b.with_children(|b| {
+            for child in node.children.iter() {
+                build_template_node(b, child, asset_server);
+            }
+        });
+    }
+}
+
+pub trait UiBuilderTemplateExt {
+    fn template(&mut self, template: &UiTemplate, asset_server: &AssetServer) -> &mut Self;
+
+    fn with_template(&mut self, template: Handle<UiTemplate>) -> &mut Self;
+}
+
+impl<'a, B: UiBuilderBackend, C> UiBuilderTemplateExt for UiBuilder<'a, B, C> {
+    /// create template nodes under current parent, last entity is template root
+    fn template(&mut self, template: &UiTemplate, asset_server: &AssetServer) -> &mut Self {
+        let old_text_style = self.default_text_style.clone();
+        if let Some(text_style) = &template.text_style {
+            self.default_text_style = TextStyle {
+                font: text_style
+                    .font
+                    .as_ref()
+                    .map(|path| asset_server.load(path.as_str()))
+                    .unwrap_or_else(|| old_text_style.font.clone()),
+                font_size: text_style.font_size,
+                color: text_style.color,
+            };
+        }
+        build_template_node(self, &template.root, asset_server);
+        self.default_text_style = old_text_style;
+        self
+    }
+
+    /// spawn template as children of last entity once the asset is loaded
+    fn with_template(&mut self, template: Handle<UiTemplate>) -> &mut Self {
+        self.insert_last(UiTemplateRoot(template));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UiBuilderPlugin;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(bevy::input::InputPlugin)
+            .add_plugin(UiBuilderPlugin);
+        app
+    }
+
+    fn menu_template() -> UiTemplate {
+        ron::de::from_str(include_str!("../assets/ui/menu.ui.ron")).unwrap()
+    }
+
+    fn spawn_template_root(app: &mut App, template: UiTemplate) -> (Handle<UiTemplate>, Entity) {
+        let handle = app.world.resource_mut::<Assets<UiTemplate>>().add(template);
+        let root = app
+            .world
+            .spawn((NodeBundle::default(), UiTemplateRoot(handle.clone())))
+            .id();
+        app.update();
+        (handle, root)
+    }
+
+    #[test]
+    fn hot_reload_preserves_named_node_state() {
+        let mut app = test_app();
+        let (handle, root) = spawn_template_root(&mut app, menu_template());
+        let sound = app.world.resource::<UiNames>().get("sound").unwrap();
+        assert!(!app.world.get::<ToggleButton>(sound).unwrap().toggled);
+        app.world.get_mut::<ToggleButton>(sound).unwrap().toggled = true;
+
+        // modified asset rebuilds subtree
+        app.world
+            .resource_mut::<Assets<UiTemplate>>()
+            .get_mut(&handle)
+            .unwrap()
+            .root
+            .children
+            .push(UiTemplateNode {
+                kind: UiTemplateNodeKind::Text("added".into()),
+                ..default()
+            });
+        app.update();
+        app.update();
+
+        let new_sound = app.world.resource::<UiNames>().get("sound").unwrap();
+        assert_ne!(new_sound, sound);
+        assert!(app.world.get_entity(sound).is_none());
+        assert!(app.world.get::<ToggleButton>(new_sound).unwrap().toggled);
+        let menu = app.world.get::<Children>(root).unwrap()[0];
+        assert_eq!(app.world.get::<Children>(menu).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn template_loaded_from_file_spawns_nodes() {
+        let mut app = test_app();
+        let handle = app
+            .world
+            .resource::<AssetServer>()
+            .load::<UiTemplate, _>("ui/menu.ui.ron");
+        let root = {
+            let mut b = UiBuilder::new(&mut app.world, ());
+            b.node().with_template(handle);
+            let root = b.last();
+            b.finish().unwrap();
+            root
+        };
+        for _ in 0..200 {
+            app.update();
+            if app.world.get::<UiTemplateSpawned>(root).is_some() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert!(app.world.get::<UiTemplateSpawned>(root).is_some());
+
+        let menu = app.world.get::<Children>(root).unwrap()[0];
+        assert_eq!(app.world.get::<Name>(menu).unwrap().as_str(), "menu");
+        let children = app.world.get::<Children>(menu).unwrap().to_vec();
+        assert_eq!(children.len(), 3);
+        let title = app.world.get::<Text>(children[0]).unwrap();
+        assert_eq!(title.sections[0].value, "Menu");
+        assert_eq!(title.sections[0].style.font_size, 32.0);
+
+        let names = app.world.resource::<UiNames>();
+        assert_eq!(names.get("start"), Some(children[1]));
+        assert_eq!(names.get("sound"), Some(children[2]));
+        assert!(app.world.get::<ColorButton>(children[1]).is_some());
+        assert!(app.world.get::<ToggleButton>(children[2]).is_some());
+    }
+
+    #[test]
+    fn template_binds_map_onto_reflect_bind() {
+        let mut app = test_app();
+        app.register_type::<Text>();
+        let template: UiTemplate = ron::de::from_str(
+            r#"#![enable(implicit_some)]
+            (
+                root: (
+                    children: [
+                        (unique_name: "source", disabled: true),
+                        (
+                            kind: Text(""),
+                            binds: [(
+                                source: "source",
+                                source_component: "Disabled",
+                                source_path: "0",
+                                target_component: "Text",
+                                target_path: "sections[0].value",
+                            )],
+                        ),
+                    ],
+                ),
+            )"#,
+        )
+        .unwrap();
+        let (_, root) = spawn_template_root(&mut app, template);
+        app.update();
+
+        let node = app.world.get::<Children>(root).unwrap()[0];
+        let text = app.world.get::<Children>(node).unwrap()[1];
+        assert_eq!(
+            app.world.get::<Text>(text).unwrap().sections[0].value,
+            "true"
+        );
+    }
+}
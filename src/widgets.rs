@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+
+use crate::{backend::UiBuilderBackend, builder::UiBuilder, buttons::*};
+
+/// reusable widget, struct fields are props
+///
+/// build with `b.widget(MyWidget { .. })`, widget should create one root node
+/// at current level, so last entity is widget root after build, child content
+/// can be added with `.with_children()`
+///
+/// implement `UiWidget<MyContext>` if widget need to read builder context
+pub trait UiWidget<C = ()> {
+    fn build<B: UiBuilderBackend>(self, b: &mut UiBuilder<B, C>);
+}
+
+/// button with ColorButton visual and optional text label
+#[derive(Debug, Clone)]
+pub struct ColorButtonWidget {
+    pub colors: ColorButton,
+    pub style: Style,
+    pub label: Option<String>,
+}
+
+impl Default for ColorButtonWidget {
+    fn default() -> Self {
+        Self {
+            colors: ColorButton::new(Color::GRAY),
+            style: Style {
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            label: None,
+        }
+    }
+}
+
+impl<C> UiWidget<C> for ColorButtonWidget {
+    fn build<B: UiBuilderBackend>(self, b: &mut UiBuilder<B, C>) {
+        b.button()
+            .with_style_modifier(self.style)
+            .with_color_button(self.colors);
+        if let Some(label) = self.label {
+            b.with_children(|b| {
+                b.text(label);
+            });
+        }
+    }
+}
+
+/// toggle button with ColorButton visual, optional toggle group and text label
+#[derive(Debug, Clone, Default)]
+pub struct ToggleButtonWidget {
+    pub toggled: bool,
+    pub group: Option<String>,
+    pub button: ColorButtonWidget,
+}
+
+impl<C> UiWidget<C> for ToggleButtonWidget {
+    fn build<B: UiBuilderBackend>(self, b: &mut UiBuilder<B, C>) {
+        b.widget(self.button).with_toggle(self.toggled);
+        if let Some(group) = self.group {
+            b.with_toggle_group(group);
+        }
+    }
+}
+
+/// button with ImageButton visual
+#[derive(Debug, Clone, Default)]
+pub struct ImageButtonWidget {
+    pub images: ImageButton,
+    pub style: Style,
+}
+
+impl<C> UiWidget<C> for ImageButtonWidget {
+    fn build<B: UiBuilderBackend>(self, b: &mut UiBuilder<B, C>) {
+        b.button()
+            .with_style_modifier(self.style)
+            .with_component(UiImage(self.images.normal.clone()))
+            .with_image_button(self.images);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// reads label color from context
+    struct Title(&'static str);
+
+    impl UiWidget<Color> for Title {
+        fn build<B: UiBuilderBackend>(self, b: &mut UiBuilder<B, Color>) {
+            b.default_text_style.color = b.context;
+            b.node().with_children(|b| {
+                b.text(self.0);
+            });
+        }
+    }
+
+    fn single_child(world: &World, entity: Entity) -> Entity {
+        let children = world.get::<Children>(entity).unwrap();
+        assert_eq!(children.len(), 1);
+        children[0]
+    }
+
+    #[test]
+    fn custom_widget_reads_context() {
+        let mut world = World::new();
+        let root = {
+            let mut b = UiBuilder::new(&mut world, Color::RED);
+            b.widget(Title("title")).with_name("title");
+            b.finish().unwrap();
+            b.last()
+        };
+
+        assert_eq!(world.get::<Name>(root).unwrap().as_str(), "title");
+        let text = world.get::<Text>(single_child(&world, root)).unwrap();
+        assert_eq!(text.sections[0].value, "title");
+        assert_eq!(text.sections[0].style.color, Color::RED);
+    }
+
+    #[test]
+    fn color_button_widget() {
+        let mut world = World::new();
+        let root = {
+            let mut b = UiBuilder::new(&mut world, ());
+            b.widget(ColorButtonWidget {
+                colors: ColorButton::new(Color::BLUE),
+                label: Some("ok".into()),
+                ..default()
+            });
+            b.finish().unwrap();
+            b.last()
+        };
+
+        assert!(world.get::<Button>(root).is_some());
+        assert_eq!(world.get::<BackgroundColor>(root).unwrap().0, Color::BLUE);
+        assert!(world.get::<ColorButton>(root).is_some());
+        let text = world.get::<Text>(single_child(&world, root)).unwrap();
+        assert_eq!(text.sections[0].value, "ok");
+    }
+
+    #[test]
+    fn toggle_and_image_button_widgets() {
+        let mut world = World::new();
+        let (toggle, image) = {
+            let mut b = UiBuilder::new(&mut world, ());
+            b.widget(ToggleButtonWidget {
+                toggled: true,
+                group: Some("tabs".into()),
+                ..default()
+            });
+            let toggle = b.last();
+            b.widget(ImageButtonWidget::default());
+            b.finish().unwrap();
+            (toggle, b.last())
+        };
+
+        assert!(world.get::<ToggleButton>(toggle).unwrap().toggled);
+        assert_eq!(world.get::<ToggleButtonGroup>(toggle).unwrap().0, "tabs");
+        assert!(world.get::<Children>(toggle).is_none());
+        assert!(world.get::<ImageButton>(image).is_some());
+        assert!(world.get::<UiImage>(image).is_some());
+    }
+}